@@ -1,16 +1,8 @@
 use reeds_shepp_lib::{Pose, get_all_paths, path_length};
 
 fn main() {
-    let start_pose = Pose {
-        x: 0.0,
-        y: 0.0,
-        theta_degree: 0.0,
-    };
-    let end_pose = Pose {
-        x: 2.0,
-        y: 2.0,
-        theta_degree: 90.0,
-    };
+    let start_pose = Pose::from_degrees(0.0, 0.0, 0.0);
+    let end_pose = Pose::from_degrees(2.0, 2.0, 90.0);
 
     println!("Calculating all candidate movement sequences (before optimization)...");
     println!("Start: {:?}", start_pose);
@@ -1,25 +1,21 @@
 use reeds_shepp_lib::{Pose, get_optimal_path, path_length};
 
 fn main() {
-    let start_pose = Pose {
-        x: 0.0,
-        y: 0.0,
-        theta_degree: 0.0,
-    };
-    let end_pose = Pose {
-        x: 5.0,
-        y: 3.0,
-        theta_degree: 90.0,
-    };
+    let start_pose = Pose::from_degrees(0.0, 0.0, 0.0);
+    let end_pose = Pose::from_degrees(5.0, 3.0, 90.0);
 
     println!("Calculating optimal movement sequence...");
     println!(
         "Start: x={:.2}, y={:.2}, theta={:.2}°",
-        start_pose.x, start_pose.y, start_pose.theta_degree
+        start_pose.x,
+        start_pose.y,
+        start_pose.theta_degree()
     );
     println!(
         "End:   x={:.2}, y={:.2}, theta={:.2}°",
-        end_pose.x, end_pose.y, end_pose.theta_degree
+        end_pose.x,
+        end_pose.y,
+        end_pose.theta_degree()
     );
 
     match get_optimal_path(start_pose, end_pose) {
@@ -50,23 +46,19 @@ fn main() {
     }
 
     println!("\n--- Another example ---");
-    let start_pose_2 = Pose {
-        x: 1.0,
-        y: 1.0,
-        theta_degree: 45.0,
-    };
-    let end_pose_2 = Pose {
-        x: -2.0,
-        y: 4.0,
-        theta_degree: -30.0,
-    };
+    let start_pose_2 = Pose::from_degrees(1.0, 1.0, 45.0);
+    let end_pose_2 = Pose::from_degrees(-2.0, 4.0, -30.0);
     println!(
         "Start: x={:.2}, y={:.2}, theta={:.2}°",
-        start_pose_2.x, start_pose_2.y, start_pose_2.theta_degree
+        start_pose_2.x,
+        start_pose_2.y,
+        start_pose_2.theta_degree()
     );
     println!(
         "End:   x={:.2}, y={:.2}, theta={:.2}°",
-        end_pose_2.x, end_pose_2.y, end_pose_2.theta_degree
+        end_pose_2.x,
+        end_pose_2.y,
+        end_pose_2.theta_degree()
     );
 
     if let Some(path) = get_optimal_path(start_pose_2, end_pose_2) {
@@ -32,66 +32,26 @@ fn process_path_request(id: &str, start: Pose, end: Pose) {
 
 fn main() {
     // Case 1: A typical sequence
-    let start1 = Pose {
-        x: 0.0,
-        y: 0.0,
-        theta_degree: 0.0,
-    };
-    let end1 = Pose {
-        x: 7.0,
-        y: -2.0,
-        theta_degree: -45.0,
-    };
+    let start1 = Pose::from_degrees(0.0, 0.0, 0.0);
+    let end1 = Pose::from_degrees(7.0, -2.0, -45.0);
     process_path_request("Typical", start1, end1);
 
     // Case 2: Start and End poses are identical
-    let start2 = Pose {
-        x: 1.0,
-        y: 1.0,
-        theta_degree: 90.0,
-    };
-    let end2 = Pose {
-        x: 1.0,
-        y: 1.0,
-        theta_degree: 90.0,
-    };
+    let start2 = Pose::from_degrees(1.0, 1.0, 90.0);
+    let end2 = Pose::from_degrees(1.0, 1.0, 90.0);
     process_path_request("Identical Poses", start2, end2);
 
     // Case 3: Start and End poses are very close
-    let start3 = Pose {
-        x: 0.0,
-        y: 0.0,
-        theta_degree: 0.0,
-    };
-    let end3 = Pose {
-        x: 0.001,
-        y: 0.001,
-        theta_degree: 1.0,
-    };
+    let start3 = Pose::from_degrees(0.0, 0.0, 0.0);
+    let end3 = Pose::from_degrees(0.001, 0.001, 1.0);
     process_path_request("Very Close Poses", start3, end3);
 
     // Case 4: Poses that might require more complex maneuvers or test edge conditions
-    let start4 = Pose {
-        x: 0.0,
-        y: 0.0,
-        theta_degree: 0.0,
-    };
-    let end4 = Pose {
-        x: 0.1,
-        y: 0.1,
-        theta_degree: 170.0,
-    }; // Short distance, large turn
+    let start4 = Pose::from_degrees(0.0, 0.0, 0.0);
+    let end4 = Pose::from_degrees(0.1, 0.1, 170.0); // Short distance, large turn
     process_path_request("Challenging Short Turn", start4, end4);
 
-    let start5 = Pose {
-        x: 0.0,
-        y: 0.0,
-        theta_degree: 0.0,
-    };
-    let end5 = Pose {
-        x: 100.0,
-        y: 0.0,
-        theta_degree: 0.0,
-    }; // Long straight sequence
+    let start5 = Pose::from_degrees(0.0, 0.0, 0.0);
+    let end5 = Pose::from_degrees(100.0, 0.0, 0.0); // Long straight sequence
     process_path_request("Long Straight Sequence", start5, end5);
 }
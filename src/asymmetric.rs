@@ -0,0 +1,344 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::trajectory;
+use crate::utils;
+use crate::{Path, PathElement, Pose, Steering, get_raw_family_paths};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TurningRadii {
+    pub left: f64,
+    pub right: f64,
+}
+
+impl TurningRadii {
+    pub fn new(left: f64, right: f64) -> Self {
+        TurningRadii { left, right }
+    }
+
+    /// Straights report a radius of 1 so that `param * radius` is the length of every
+    /// element of an [`AsymmetricPath`].
+    pub fn radius(&self, steering: Steering) -> f64 {
+        match steering {
+            Steering::Left => self.left,
+            Steering::Right => self.right,
+            Steering::Straight => 1.,
+        }
+    }
+}
+
+/// Arcs store their turning angle and straights their length, so a single radius cannot
+/// scale the elements. `radii` holds the radius of each arc and 1 for each straight, so
+/// `param * radii[i]` is always the element length.
+#[derive(Debug, Clone)]
+pub struct AsymmetricPath {
+    pub path: Path,
+    pub radii: Vec<f64>,
+    pub length: f64,
+}
+
+impl AsymmetricPath {
+    fn from_solved(solved: Vec<(PathElement, f64)>) -> Self {
+        let length = solved
+            .iter()
+            .map(|(e, radius)| e.param.abs() * radius)
+            .sum();
+        let (path, radii) = solved.into_iter().unzip();
+        AsymmetricPath {
+            path,
+            radii,
+            length,
+        }
+    }
+
+    pub fn element_length(&self, index: usize) -> f64 {
+        self.path[index].param.abs() * self.radii[index]
+    }
+
+    pub fn end_pose(&self, start: Pose) -> Pose {
+        self.pose_at(start, self.length)
+    }
+
+    pub fn pose_at(&self, start: Pose, arc_length: f64) -> Pose {
+        let mut pose = start;
+        let mut remaining = arc_length.max(0.);
+        for (i, e) in self.path.iter().enumerate() {
+            let length = self.element_length(i);
+            if remaining <= length {
+                return trajectory::advance(&pose, e, remaining, self.radii[i]);
+            }
+            pose = trajectory::advance(&pose, e, length, self.radii[i]);
+            remaining -= length;
+        }
+        pose
+    }
+
+    fn same_as(&self, other: &AsymmetricPath) -> bool {
+        self.path.len() == other.path.len()
+            && self.path.iter().zip(&other.path).all(|(a, b)| {
+                a.steering == b.steering && a.gear == b.gear && (a.param - b.param).abs() <= 1e-9
+            })
+    }
+}
+
+/// Which of the three unknowns `t`, `u`, `v` drives each segment of a family's word; `None`
+/// marks the quarter turns that the families fix.
+const SLOTS: [&[Option<usize>]; 12] = [
+    &[Some(0), Some(1), Some(2)],
+    &[Some(0), Some(1), Some(2)],
+    &[Some(0), Some(1), Some(2)],
+    &[Some(0), Some(1), Some(2)],
+    &[Some(0), Some(1), Some(2)],
+    &[Some(0), Some(1), Some(1), Some(2)],
+    &[Some(0), Some(1), Some(1), Some(2)],
+    &[Some(0), None, Some(1), Some(2)],
+    &[Some(0), Some(1), None, Some(2)],
+    &[Some(0), None, Some(1), Some(2)],
+    &[Some(0), Some(1), None, Some(2)],
+    &[Some(0), None, Some(1), None, Some(2)],
+];
+
+pub(crate) struct Word {
+    family: usize,
+    elements: Path,
+}
+
+/// Symmetric solutions for `seed_radius`, used as the words and starting points of the
+/// solver.
+pub(crate) fn seed_words(start: Pose, end: Pose, seed_radius: f64) -> Vec<(Word, [f64; 3])> {
+    get_raw_family_paths(
+        utils::scale_pose(&start, 1. / seed_radius),
+        utils::scale_pose(&end, 1. / seed_radius),
+    )
+    .into_iter()
+    .filter(|(_, elements)| !elements.is_empty())
+    .map(|(family, elements)| {
+        let word = Word { family, elements };
+        let seed = word.seed(seed_radius);
+        (word, seed)
+    })
+    .collect()
+}
+
+impl Word {
+    pub(crate) fn elements(&self) -> &Path {
+        &self.elements
+    }
+
+    fn build(&self, unknowns: &[f64; 3]) -> Path {
+        self.elements
+            .iter()
+            .zip(SLOTS[self.family])
+            .map(|(e, slot)| PathElement {
+                param: slot.map_or(FRAC_PI_2, |i| unknowns[i]),
+                ..e.clone()
+            })
+            .collect()
+    }
+
+    fn residual(&self, start: Pose, goal: Pose, radii: &[f64], unknowns: &[f64; 3]) -> [f64; 3] {
+        let end = self
+            .build(unknowns)
+            .iter()
+            .zip(radii)
+            .fold(start, |pose, (e, &radius)| {
+                trajectory::advance(&pose, e, e.param * radius, radius)
+            });
+        [
+            end.x - goal.x,
+            end.y - goal.y,
+            utils::normalize_angle_rad(end.theta_radians - goal.theta_radians),
+        ]
+    }
+
+    /// Damped Newton iteration with a finite-difference Jacobian. `radii` holds the radius of
+    /// every element, with 1 for straights, and is returned alongside the surviving elements.
+    pub(crate) fn solve(
+        &self,
+        start: Pose,
+        goal: Pose,
+        radii: &[f64],
+        seed: [f64; 3],
+    ) -> Option<Vec<(PathElement, f64)>> {
+        let mut unknowns = seed;
+        let mut residual = self.residual(start, goal, radii, &unknowns);
+        for _ in 0..50 {
+            if norm(&residual) < 1e-10 {
+                break;
+            }
+            let mut jacobian = [[0.; 3]; 3];
+            for j in 0..3 {
+                let mut shifted = unknowns;
+                shifted[j] += 1e-7;
+                let r = self.residual(start, goal, radii, &shifted);
+                for i in 0..3 {
+                    jacobian[i][j] = (r[i] - residual[i]) / 1e-7;
+                }
+            }
+            let step = solve_3x3(jacobian, residual)?;
+
+            let mut scale = 1.;
+            loop {
+                let candidate = [
+                    unknowns[0] - scale * step[0],
+                    unknowns[1] - scale * step[1],
+                    unknowns[2] - scale * step[2],
+                ];
+                let r = self.residual(start, goal, radii, &candidate);
+                if norm(&r) < norm(&residual) {
+                    unknowns = candidate;
+                    residual = r;
+                    break;
+                }
+                scale /= 2.;
+                if scale < 1e-6 {
+                    return None;
+                }
+            }
+        }
+        if norm(&residual) > 1e-8 {
+            return None;
+        }
+
+        // A full turn leaves the pose unchanged, so every solved arc is shortened to at most
+        // half a turn.
+        for (i, e) in self.elements.iter().enumerate() {
+            if let Some(slot) = SLOTS[self.family][i]
+                && e.steering != Steering::Straight
+            {
+                unknowns[slot] = utils::normalize_angle_rad(unknowns[slot]);
+            }
+        }
+
+        Some(
+            self.build(&unknowns)
+                .into_iter()
+                .zip(radii.iter().copied())
+                .filter(|(e, _)| e.param.abs() > 1e-10)
+                .map(|(e, radius)| (PathElement::create(e.param, e.steering, e.gear), radius))
+                .collect(),
+        )
+    }
+
+    fn seed(&self, radius: f64) -> [f64; 3] {
+        let mut unknowns = [0.; 3];
+        for (e, slot) in self.elements.iter().zip(SLOTS[self.family]) {
+            if let Some(i) = slot {
+                unknowns[*i] = match e.steering {
+                    Steering::Straight => e.param * radius,
+                    Steering::Left | Steering::Right => e.param,
+                };
+            }
+        }
+        unknowns
+    }
+}
+
+fn norm(v: &[f64; 3]) -> f64 {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
+fn solve_3x3(a: [[f64; 3]; 3], b: [f64; 3]) -> Option<[f64; 3]> {
+    let det = |m: [[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d = det(a);
+    if d.abs() < 1e-14 {
+        return None;
+    }
+    let mut x = [0.; 3];
+    for (j, value) in x.iter_mut().enumerate() {
+        let mut m = a;
+        for i in 0..3 {
+            m[i][j] = b[i];
+        }
+        *value = det(m) / d;
+    }
+    Some(x)
+}
+
+/// Reflection no longer maps solutions onto each other because it swaps the radii, so every
+/// word of every family is solved on its own. Words are seeded with the symmetric solutions
+/// for the left, right and mean radius and then solved exactly for the asymmetric radii.
+/// Seeds often converge to the same solution, which is returned once.
+pub fn get_all_paths_asymmetric(
+    start: Pose,
+    end: Pose,
+    radii: &TurningRadii,
+) -> Vec<AsymmetricPath> {
+    let mut paths: Vec<AsymmetricPath> = Vec::new();
+    for seed_radius in [radii.left, radii.right, (radii.left + radii.right) / 2.] {
+        for (word, seed) in seed_words(start, end, seed_radius) {
+            let element_radii: Vec<f64> = word
+                .elements()
+                .iter()
+                .map(|e| radii.radius(e.steering))
+                .collect();
+            if let Some(solved) = word.solve(start, end, &element_radii, seed)
+                && !solved.is_empty()
+            {
+                let candidate = AsymmetricPath::from_solved(solved);
+                if !paths.iter().any(|path| path.same_as(&candidate)) {
+                    paths.push(candidate);
+                }
+            }
+        }
+    }
+    paths
+}
+
+pub fn get_optimal_path_asymmetric(
+    start: Pose,
+    end: Pose,
+    radii: &TurningRadii,
+) -> Option<AsymmetricPath> {
+    get_all_paths_asymmetric(start, end, radii)
+        .into_iter()
+        .min_by(|a, b| a.length.total_cmp(&b.length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reeds_shepp_distance;
+    use crate::sampling::{Bounds, Rng};
+
+    #[test]
+    fn equal_radii_reproduce_reeds_shepp() {
+        let bounds = Bounds::new(-6., 6., -6., 6.);
+        let mut rng = Rng::new(3);
+        for radius in [0.5, 1., 2.5] {
+            let radii = TurningRadii::new(radius, radius);
+            for _ in 0..50 {
+                let (start, goal) = (bounds.sample(&mut rng), bounds.sample(&mut rng));
+                let path = get_optimal_path_asymmetric(start, goal, &radii).unwrap();
+                let expected = reeds_shepp_distance(start, goal, radius);
+                assert!(
+                    (path.length - expected).abs() < 1e-6,
+                    "{} {expected}",
+                    path.length
+                );
+
+                let end = path.end_pose(start);
+                assert!((end.x - goal.x).abs() < 1e-6 && (end.y - goal.y).abs() < 1e-6);
+                assert!(
+                    utils::normalize_angle_rad(end.theta_radians - goal.theta_radians).abs() < 1e-6
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn candidates_are_distinct() {
+        let radii = TurningRadii::new(1., 2.);
+        let paths = get_all_paths_asymmetric(
+            Pose::from_degrees(0., 0., 0.),
+            Pose::from_degrees(3., 1., 60.),
+            &radii,
+        );
+        for (i, a) in paths.iter().enumerate() {
+            assert!(paths[i + 1..].iter().all(|b| !a.same_as(b)));
+        }
+    }
+}
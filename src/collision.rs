@@ -0,0 +1,290 @@
+use crate::polygon::Polygon;
+use crate::trajectory;
+use crate::utils;
+use crate::{Path, Pose, Steering};
+
+#[derive(Debug, Clone)]
+pub struct OccupancyGrid {
+    pub resolution: f64,
+    pub origin_x: f64,
+    pub origin_y: f64,
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<bool>,
+}
+
+impl OccupancyGrid {
+    pub fn new(resolution: f64, origin_x: f64, origin_y: f64, width: usize, height: usize) -> Self {
+        OccupancyGrid {
+            resolution,
+            origin_x,
+            origin_y,
+            width,
+            height,
+            cells: vec![false; width * height],
+        }
+    }
+
+    pub fn set_occupied(&mut self, col: usize, row: usize, occupied: bool) {
+        if col < self.width && row < self.height {
+            self.cells[row * self.width + col] = occupied;
+        }
+    }
+
+    pub fn cell_at(&self, x: f64, y: f64) -> Option<(usize, usize)> {
+        let col = ((x - self.origin_x) / self.resolution).floor();
+        let row = ((y - self.origin_y) / self.resolution).floor();
+        if col < 0. || row < 0. || col >= self.width as f64 || row >= self.height as f64 {
+            return None;
+        }
+        Some((col as usize, row as usize))
+    }
+
+    /// Points outside the grid count as occupied so paths cannot leave the known map.
+    pub fn is_occupied_at(&self, x: f64, y: f64) -> bool {
+        match self.cell_at(x, y) {
+            Some((col, row)) => self.cells[row * self.width + col],
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Footprint {
+    pub front: f64,
+    pub rear: f64,
+    pub width: f64,
+}
+
+impl Footprint {
+    pub fn new(front: f64, rear: f64, width: f64) -> Self {
+        Footprint { front, rear, width }
+    }
+
+    pub fn corners(&self, pose: &Pose) -> [(f64, f64); 4] {
+        let half = self.width / 2.;
+        [
+            (self.front, half),
+            (-self.rear, half),
+            (-self.rear, -half),
+            (self.front, -half),
+        ]
+        .map(|(x, y)| utils::local_to_world(pose, x, y))
+    }
+
+    pub fn polygon(&self) -> Polygon {
+        Polygon::rectangle(self.front, self.rear, self.width)
+    }
+
+    pub fn bounding_radius(&self) -> f64 {
+        self.front.abs().max(self.rear.abs()).hypot(self.width / 2.)
+    }
+
+    fn sample_points(&self, spacing: f64) -> Vec<(f64, f64)> {
+        let length = self.front + self.rear;
+        let along = (length / spacing).ceil().max(1.) as usize;
+        let across = (self.width / spacing).ceil().max(1.) as usize;
+
+        let mut points = Vec::with_capacity((along + 1) * (across + 1));
+        for i in 0..=along {
+            for j in 0..=across {
+                points.push((
+                    -self.rear + length * i as f64 / along as f64,
+                    -self.width / 2. + self.width * j as f64 / across as f64,
+                ));
+            }
+        }
+        points
+    }
+}
+
+pub fn footprint_collides(grid: &OccupancyGrid, footprint: &Footprint, pose: &Pose) -> bool {
+    points_collide(grid, &footprint.sample_points(grid.resolution / 2.), pose)
+}
+
+fn points_collide(grid: &OccupancyGrid, points: &[(f64, f64)], pose: &Pose) -> bool {
+    points.iter().any(|&(x, y)| {
+        let (x, y) = utils::local_to_world(pose, x, y);
+        grid.is_occupied_at(x, y)
+    })
+}
+
+/// The step keeps every footprint point within half a cell of its previous sample,
+/// on arcs as well as on straights.
+pub fn collision_step(grid: &OccupancyGrid, footprint: &Footprint, radius: f64) -> f64 {
+    grid.resolution / 2. * radius / (radius + footprint.bounding_radius())
+}
+
+pub fn first_collision(
+    grid: &OccupancyGrid,
+    footprint: &Footprint,
+    start: Pose,
+    path: &Path,
+    radius: f64,
+) -> Option<f64> {
+    let points = footprint.sample_points(grid.resolution / 2.);
+    let step = collision_step(grid, footprint, radius);
+
+    trajectory::sample(start, path, radius, step)
+        .into_iter()
+        .find(|(_, pose)| points_collide(grid, &points, pose))
+        .map(|(arc_length, _)| arc_length)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Clearance {
+    pub clearance: f64,
+    pub arc_length: f64,
+    pub pose: Pose,
+}
+
+impl Clearance {
+    pub fn is_free(&self) -> bool {
+        self.clearance > 0.
+    }
+}
+
+pub fn pose_clearance(obstacles: &[Polygon], footprint: &Polygon, pose: &Pose) -> f64 {
+    let placed = footprint.transformed(pose);
+    obstacles
+        .iter()
+        .map(|obstacle| placed.clearance(obstacle))
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Samples are spaced by how far the footprint may move before its clearance could drop
+/// more than `tolerance` below the smallest clearance seen so far, so the result is within
+/// `tolerance` of the true minimum.
+pub fn path_clearance(
+    obstacles: &[Polygon],
+    footprint: &Polygon,
+    start: Pose,
+    path: &Path,
+    radius: f64,
+    tolerance: f64,
+) -> Clearance {
+    let tolerance = tolerance.max(1e-9);
+    let mut minimum = Clearance {
+        clearance: pose_clearance(obstacles, footprint, &start),
+        arc_length: 0.,
+        pose: start,
+    };
+
+    let mut pose = start;
+    let mut travelled = 0.;
+    for e in path {
+        let length = trajectory::element_length(e, radius);
+        let sweep_factor = match e.steering {
+            Steering::Straight => 1.,
+            Steering::Left | Steering::Right => (radius + footprint.bounding_radius()) / radius,
+        };
+
+        let mut distance = 0.;
+        while distance < length {
+            let here = trajectory::advance(&pose, e, distance, radius);
+            let clearance = pose_clearance(obstacles, footprint, &here);
+            if clearance < minimum.clearance {
+                minimum = Clearance {
+                    clearance,
+                    arc_length: travelled + distance,
+                    pose: here,
+                };
+            }
+            if clearance.is_infinite() {
+                break;
+            }
+            distance += (clearance - minimum.clearance + tolerance) / sweep_factor;
+        }
+
+        pose = trajectory::advance(&pose, e, length, radius);
+        travelled += length;
+        let clearance = pose_clearance(obstacles, footprint, &pose);
+        if clearance < minimum.clearance {
+            minimum = Clearance {
+                clearance,
+                arc_length: travelled,
+                pose,
+            };
+        }
+    }
+
+    minimum
+}
+
+pub trait CollisionChecker {
+    fn is_free(&self, start: Pose, path: &Path, radius: f64) -> bool;
+}
+
+impl<F> CollisionChecker for F
+where
+    F: Fn(Pose, &Path, f64) -> bool,
+{
+    fn is_free(&self, start: Pose, path: &Path, radius: f64) -> bool {
+        self(start, path, radius)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GridChecker<'a> {
+    pub grid: &'a OccupancyGrid,
+    pub footprint: Footprint,
+}
+
+impl CollisionChecker for GridChecker<'_> {
+    fn is_free(&self, start: Pose, path: &Path, radius: f64) -> bool {
+        first_collision(self.grid, &self.footprint, start, path, radius).is_none()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PolygonChecker<'a> {
+    pub obstacles: &'a [Polygon],
+    pub footprint: &'a Polygon,
+    pub tolerance: f64,
+}
+
+impl CollisionChecker for PolygonChecker<'_> {
+    fn is_free(&self, start: Pose, path: &Path, radius: f64) -> bool {
+        path_clearance(
+            self.obstacles,
+            self.footprint,
+            start,
+            path,
+            radius,
+            self.tolerance,
+        )
+        .is_free()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Degrees, Gear, PathElement};
+
+    #[test]
+    fn first_collision_reports_the_arc_length_of_the_hit() {
+        let mut grid = OccupancyGrid::new(0.1, 0., 0., 40, 20);
+        grid.set_occupied(25, 10, true);
+        let footprint = Footprint::new(0.2, 0.2, 0.2);
+        let start = Pose::new(0.5, 1.05, Degrees(0.));
+        let straight = |gear| {
+            vec![PathElement {
+                param: 2.,
+                steering: Steering::Straight,
+                gear,
+            }]
+        };
+
+        // The front edge reaches the occupied cell at x = 2.5 after 1.8 m.
+        let hit = first_collision(&grid, &footprint, start, &straight(Gear::Forward), 1.).unwrap();
+        assert!((1.8..=1.8 + collision_step(&grid, &footprint, 1.)).contains(&hit));
+        // Reversing leaves the known map, which counts as occupied.
+        assert!(
+            first_collision(&grid, &footprint, start, &straight(Gear::Backwards), 1.).is_some()
+        );
+        // At half the radius the same parameter stops 0.8 m short of the cell.
+        let short = first_collision(&grid, &footprint, start, &straight(Gear::Forward), 0.5);
+        assert!(short.is_none());
+    }
+}
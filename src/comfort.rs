@@ -0,0 +1,148 @@
+use crate::asymmetric::seed_words;
+use crate::trajectory;
+use crate::{Path, PathElement, Pose, Steering};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComfortRange {
+    pub preferred: f64,
+    pub minimum: f64,
+    pub weight: f64,
+    pub levels: usize,
+}
+
+impl ComfortRange {
+    pub fn radii(&self) -> Vec<f64> {
+        let levels = self.levels.max(2);
+        (0..levels)
+            .map(|i| {
+                self.preferred + (self.minimum - self.preferred) * i as f64 / (levels - 1) as f64
+            })
+            .collect()
+    }
+
+    /// Tighter arcs cost their length times how much their curvature exceeds the preferred
+    /// curvature.
+    pub fn discomfort(&self, arc_length: f64, radius: f64) -> f64 {
+        self.weight * arc_length * (1. / radius - 1. / self.preferred).max(0.)
+    }
+}
+
+/// Arcs store their turning angle and straights their length; `radii` holds the radius of
+/// each arc and 1 for each straight, so `param * radii[i]` is always the element length.
+#[derive(Debug, Clone)]
+pub struct ComfortPath {
+    pub path: Path,
+    pub radii: Vec<f64>,
+    pub length: f64,
+    pub cost: f64,
+}
+
+impl ComfortPath {
+    pub fn element_length(&self, index: usize) -> f64 {
+        self.path[index].param.abs() * self.radii[index]
+    }
+
+    pub fn end_pose(&self, start: Pose) -> Pose {
+        self.path
+            .iter()
+            .zip(&self.radii)
+            .fold(start, |pose, (e, &radius)| {
+                trajectory::advance(&pose, e, e.param.abs() * radius, radius)
+            })
+    }
+}
+
+/// Every word is solved for every assignment of the discretized radii to its arcs, seeded with
+/// the symmetric solutions for the preferred and the minimum radius.
+pub fn get_comfort_path(start: Pose, end: Pose, range: &ComfortRange) -> Option<ComfortPath> {
+    let levels = range.radii();
+    let mut best: Option<ComfortPath> = None;
+
+    for seed_radius in [range.preferred, range.minimum] {
+        for (word, seed) in seed_words(start, end, seed_radius) {
+            let arcs = word
+                .elements()
+                .iter()
+                .filter(|e| e.steering != Steering::Straight)
+                .count();
+            for combination in 0..levels.len().pow(arcs as u32) {
+                let mut code = combination;
+                let element_radii: Vec<f64> = word
+                    .elements()
+                    .iter()
+                    .map(|e| match e.steering {
+                        Steering::Straight => 1.,
+                        Steering::Left | Steering::Right => {
+                            let radius = levels[code % levels.len()];
+                            code /= levels.len();
+                            radius
+                        }
+                    })
+                    .collect();
+
+                let Some(solved) = word.solve(start, end, &element_radii, seed) else {
+                    continue;
+                };
+                if solved.is_empty() {
+                    continue;
+                }
+                let candidate = evaluate(solved, range);
+                if best.as_ref().is_none_or(|b| candidate.cost < b.cost) {
+                    best = Some(candidate);
+                }
+            }
+        }
+    }
+
+    best
+}
+
+fn evaluate(solved: Vec<(PathElement, f64)>, range: &ComfortRange) -> ComfortPath {
+    let mut length = 0.;
+    let mut discomfort = 0.;
+    for (e, radius) in &solved {
+        let element_length = e.param.abs() * radius;
+        length += element_length;
+        if e.steering != Steering::Straight {
+            discomfort += range.discomfort(element_length, *radius);
+        }
+    }
+    let (path, radii) = solved.into_iter().unzip();
+    ComfortPath {
+        path,
+        radii,
+        length,
+        cost: length + discomfort,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sampling::{Bounds, Rng};
+    use crate::utils;
+
+    #[test]
+    fn comfort_paths_end_at_the_goal() {
+        let range = ComfortRange {
+            preferred: 4.,
+            minimum: 2.,
+            weight: 1.,
+            levels: 3,
+        };
+        let bounds = Bounds::new(-10., 10., -10., 10.);
+        let mut rng = Rng::new(21);
+        for _ in 0..10 {
+            let (start, goal) = (bounds.sample(&mut rng), bounds.sample(&mut rng));
+            let path = get_comfort_path(start, goal, &range).unwrap();
+            let end = path.end_pose(start);
+            assert!((end.x - goal.x).abs() < 1e-6 && (end.y - goal.y).abs() < 1e-6);
+            assert!(
+                utils::normalize_angle_rad(end.theta_radians - goal.theta_radians).abs() < 1e-6
+            );
+            let total: f64 = (0..path.path.len()).map(|i| path.element_length(i)).sum();
+            assert!((total - path.length).abs() < 1e-9);
+            assert!(path.cost >= path.length);
+        }
+    }
+}
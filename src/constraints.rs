@@ -0,0 +1,353 @@
+use std::fmt;
+
+use crate::trajectory;
+use crate::utils;
+use crate::{
+    Gear, Path, PathElement, Pose, Steering, concatenate, cusps, get_all_paths, path_length,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PathConstraints {
+    pub first_gear: Option<Gear>,
+    pub last_gear: Option<Gear>,
+    pub max_cusps: Option<usize>,
+    pub max_reverse_distance: Option<f64>,
+    pub min_straight_length: Option<f64>,
+    pub min_arc_length: Option<f64>,
+    pub min_reverse_length: Option<f64>,
+    pub min_cusp_distance: Option<f64>,
+    pub length_enforcement: Enforcement,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Enforcement {
+    #[default]
+    Reject,
+    Penalize {
+        weight: f64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Violation {
+    FirstGear {
+        required: Gear,
+        found: Gear,
+    },
+    LastGear {
+        required: Gear,
+        found: Gear,
+    },
+    TooManyCusps {
+        max: usize,
+        found: usize,
+    },
+    ReverseDistance {
+        max: f64,
+        found: f64,
+    },
+    ShortSegment {
+        index: usize,
+        steering: Steering,
+        gear: Gear,
+        min: f64,
+        found: f64,
+    },
+    CuspsTooClose {
+        index: usize,
+        min: f64,
+        found: f64,
+    },
+}
+
+impl Violation {
+    pub fn is_length_violation(&self) -> bool {
+        matches!(
+            self,
+            Violation::ShortSegment { .. } | Violation::CuspsTooClose { .. }
+        )
+    }
+
+    fn shortfall(&self) -> f64 {
+        match *self {
+            Violation::ShortSegment { min, found, .. }
+            | Violation::CuspsTooClose { min, found, .. } => min - found,
+            _ => 0.,
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::FirstGear { required, found } => {
+                write!(f, "first segment is {found:?}, {required:?} required")
+            }
+            Violation::LastGear { required, found } => {
+                write!(f, "last segment is {found:?}, {required:?} required")
+            }
+            Violation::TooManyCusps { max, found } => {
+                write!(f, "{found} cusps, at most {max} allowed")
+            }
+            Violation::ReverseDistance { max, found } => {
+                write!(f, "{found:.3} reversed, at most {max:.3} allowed")
+            }
+            Violation::ShortSegment {
+                index,
+                steering,
+                gear,
+                min,
+                found,
+            } => write!(
+                f,
+                "segment {index} ({steering:?}, {gear:?}) is {found:.3} long, at least {min:.3} required"
+            ),
+            Violation::CuspsTooClose { index, min, found } => write!(
+                f,
+                "cusp before segment {index} is {found:.3} after the previous cusp, at least {min:.3} required"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintError {
+    NoPath,
+    NoFeasiblePath { candidates: usize },
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintError::NoPath => write!(f, "no candidate path between the poses"),
+            ConstraintError::NoFeasiblePath { candidates } => write!(
+                f,
+                "none of the {candidates} candidate paths satisfies the constraints"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConstraintError {}
+
+impl PathConstraints {
+    pub fn violations(&self, path: &Path, radius: f64) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        if let (Some(required), Some(first)) = (self.first_gear, path.first())
+            && first.gear != required
+        {
+            violations.push(Violation::FirstGear {
+                required,
+                found: first.gear,
+            });
+        }
+        if let (Some(required), Some(last)) = (self.last_gear, path.last())
+            && last.gear != required
+        {
+            violations.push(Violation::LastGear {
+                required,
+                found: last.gear,
+            });
+        }
+        if let Some(max) = self.max_cusps {
+            let found = cusps(path);
+            if found > max {
+                violations.push(Violation::TooManyCusps { max, found });
+            }
+        }
+        if let Some(max) = self.max_reverse_distance {
+            let found: f64 = path
+                .iter()
+                .filter(|e| e.gear == Gear::Backwards)
+                .map(|e| trajectory::element_length(e, radius))
+                .sum();
+            if found > max {
+                violations.push(Violation::ReverseDistance { max, found });
+            }
+        }
+
+        // Families can split one arc or straight over consecutive elements, so lengths are
+        // checked on the merged segments and indices refer to those.
+        let segments = concatenate(vec![path.clone()]);
+        for (index, e) in segments.iter().enumerate() {
+            let Some(min) = self.min_segment_length(e) else {
+                continue;
+            };
+            let found = trajectory::element_length(e, radius);
+            if found < min {
+                violations.push(Violation::ShortSegment {
+                    index,
+                    steering: e.steering,
+                    gear: e.gear,
+                    min,
+                    found,
+                });
+            }
+        }
+
+        if let Some(min) = self.min_cusp_distance {
+            let mut previous_cusp: Option<usize> = None;
+            for index in 1..segments.len() {
+                if segments[index].gear == segments[index - 1].gear {
+                    continue;
+                }
+                if let Some(previous) = previous_cusp {
+                    let found: f64 = segments[previous..index]
+                        .iter()
+                        .map(|e| trajectory::element_length(e, radius))
+                        .sum();
+                    if found < min {
+                        violations.push(Violation::CuspsTooClose { index, min, found });
+                    }
+                }
+                previous_cusp = Some(index);
+            }
+        }
+
+        violations
+    }
+
+    pub fn is_satisfied(&self, path: &Path, radius: f64) -> bool {
+        self.violations(path, radius).is_empty()
+    }
+
+    fn min_segment_length(&self, e: &PathElement) -> Option<f64> {
+        let by_steering = match e.steering {
+            Steering::Straight => self.min_straight_length,
+            Steering::Left | Steering::Right => self.min_arc_length,
+        };
+        let by_gear = match e.gear {
+            Gear::Forward => None,
+            Gear::Backwards => self.min_reverse_length,
+        };
+        match (by_steering, by_gear) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CandidateReport {
+    pub path: Path,
+    pub length: f64,
+    pub cost: f64,
+    pub violations: Vec<Violation>,
+    pub rejected: bool,
+}
+
+/// Gear and cusp-count violations always reject a candidate. Length violations reject it
+/// too unless `length_enforcement` asks for a penalty proportional to the total shortfall.
+pub fn evaluate_candidates(
+    start: Pose,
+    end: Pose,
+    radius: f64,
+    constraints: &PathConstraints,
+) -> Vec<CandidateReport> {
+    get_all_paths(
+        utils::scale_pose(&start, 1. / radius),
+        utils::scale_pose(&end, 1. / radius),
+    )
+    .into_iter()
+    .map(|path| {
+        let length = path_length(&path) * radius;
+        let violations = constraints.violations(&path, radius);
+        let (rejected, cost) = match constraints.length_enforcement {
+            Enforcement::Reject => (!violations.is_empty(), length),
+            Enforcement::Penalize { weight } => (
+                violations.iter().any(|v| !v.is_length_violation()),
+                length + weight * violations.iter().map(Violation::shortfall).sum::<f64>(),
+            ),
+        };
+        CandidateReport {
+            path,
+            length,
+            cost,
+            violations,
+            rejected,
+        }
+    })
+    .collect()
+}
+
+pub fn get_constrained_optimal_path(
+    start: Pose,
+    end: Pose,
+    radius: f64,
+    constraints: &PathConstraints,
+) -> Result<Path, ConstraintError> {
+    let reports = evaluate_candidates(start, end, radius, constraints);
+    if reports.is_empty() {
+        return Err(ConstraintError::NoPath);
+    }
+
+    let candidates = reports.len();
+    reports
+        .into_iter()
+        .filter(|report| !report.rejected)
+        .min_by(|a, b| a.cost.total_cmp(&b.cost))
+        .map(|report| report.path)
+        .ok_or(ConstraintError::NoFeasiblePath { candidates })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::Degrees;
+
+    #[test]
+    fn split_arcs_are_measured_as_one_segment() {
+        let constraints = PathConstraints {
+            min_arc_length: Some(2.),
+            ..PathConstraints::default()
+        };
+        let split: Path = vec![
+            PathElement::create(FRAC_PI_2, Steering::Left, Gear::Forward),
+            PathElement::create(FRAC_PI_2, Steering::Left, Gear::Forward),
+        ];
+        assert!(constraints.is_satisfied(&split, 1.));
+
+        let reports = evaluate_candidates(
+            Pose::new(0., 0., Degrees(0.)),
+            Pose::new(0., 2., Degrees(180.)),
+            1.,
+            &constraints,
+        );
+        for report in reports {
+            if concatenate(vec![report.path.clone()]).len() == 1 {
+                assert!(!report.rejected, "{:?}", report.path);
+            }
+        }
+    }
+
+    #[test]
+    fn excluding_every_candidate_is_reported() {
+        let (start, end) = (
+            Pose::new(0., 0., Degrees(0.)),
+            Pose::new(5., 1., Degrees(0.)),
+        );
+        let forward = PathConstraints {
+            first_gear: Some(Gear::Forward),
+            ..PathConstraints::default()
+        };
+        let path = get_constrained_optimal_path(start, end, 1., &forward).unwrap();
+        assert_eq!(path[0].gear, Gear::Forward);
+
+        // Starting in reverse without a cusp can never end driving forward.
+        let impossible = PathConstraints {
+            first_gear: Some(Gear::Backwards),
+            last_gear: Some(Gear::Forward),
+            max_cusps: Some(0),
+            ..PathConstraints::default()
+        };
+        let candidates = evaluate_candidates(start, end, 1., &impossible).len();
+        assert!(candidates > 0);
+        assert_eq!(
+            get_constrained_optimal_path(start, end, 1., &impossible).unwrap_err(),
+            ConstraintError::NoFeasiblePath { candidates }
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use crate::nearest::lower_bound;
+use crate::utils;
+use crate::{Gear, Path, PathElement, Pose, Steering, concatenate, get_optimal_path_for_radius};
+
+pub fn pre_goal_pose(goal: Pose, approach_length: f64, gear: Gear) -> Pose {
+    let back = match gear {
+        Gear::Forward => -approach_length,
+        Gear::Backwards => approach_length,
+    };
+    let (x, y) = utils::local_to_world(&goal, back, 0.);
+    Pose { x, y, ..goal }
+}
+
+/// Any path ending in a long enough straight passes through the pre-goal pose, so the
+/// optimal path to that pose followed by the approach straight is optimal among them.
+pub fn get_docking_path(
+    start: Pose,
+    goal: Pose,
+    radius: f64,
+    approach_length: f64,
+    gear: Gear,
+) -> Option<Path> {
+    let pre_goal = pre_goal_pose(goal, approach_length.max(0.), gear);
+    let to_pre_goal = if lower_bound(start, pre_goal, radius) <= 1e-10 {
+        Vec::new()
+    } else {
+        get_optimal_path_for_radius(start, pre_goal, radius)?
+    };
+    let approach = PathElement {
+        param: approach_length.max(0.) / radius,
+        steering: Steering::Straight,
+        gear,
+    };
+    if approach.param <= 1e-10 {
+        return Some(to_pre_goal);
+    }
+    Some(concatenate(vec![to_pre_goal, vec![approach]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Degrees;
+    use crate::notation::notation;
+
+    #[test]
+    fn zero_approach_adds_no_element() {
+        let start = Pose::new(0., 0., Degrees(0.));
+        let goal = Pose::new(4., 3., Degrees(90.));
+        let path = get_docking_path(start, goal, 1., 0., Gear::Forward).unwrap();
+        assert!(path.iter().all(|e| e.param > 1e-10));
+        let optimal = get_optimal_path_for_radius(start, goal, 1.).unwrap();
+        assert_eq!(
+            format!("{:.9}", notation(&path)),
+            format!("{:.9}", notation(&optimal))
+        );
+        assert!(
+            get_docking_path(goal, goal, 1., 0., Gear::Forward)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use crate::collision::{self, Clearance, CollisionChecker, Footprint, OccupancyGrid};
+use crate::goal::{self, GoalRegion};
+use crate::hybrid_astar::{self, HybridAStarConfig};
+use crate::polygon::Polygon;
+use crate::prm::{Prm, PrmConfig};
+use crate::route::{self, Heading, Route, Waypoint};
+use crate::rrt_star::{self, RrtStarConfig};
+use crate::sampling::Bounds;
+use crate::swept::{self, SweptCover};
+use crate::trajectory;
+use crate::utils;
+use crate::{Path, Pose, get_all_paths, get_optimal_path_for_radius};
+
+/// The planner works in a y-up frame with counter-clockwise headings. Image coordinates put
+/// y down and measure headings clockwise, which is the same picture mirrored across the
+/// x axis, so poses map between the two by negating `y` and the heading. The mirror is also
+/// how the image is viewed, so a left turn stays a left turn: paths, and the instructions and
+/// notation rendered from them, are identical in both conventions.
+///
+/// The methods below take and return values in the chosen convention. Grids, polygons,
+/// bounds, goal regions and waypoints are mirrored on the way in, and poses and polygons on
+/// the way out. Collision checkers passed to the sampling planners run inside the planner,
+/// so they must be built from converted inputs, e.g. a `GridChecker` over
+/// [`FrameConvention::grid_to_internal`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum FrameConvention {
+    #[default]
+    YUpCounterClockwise,
+    YDownClockwise,
+}
+
+impl FrameConvention {
+    pub fn to_internal(&self, pose: &Pose) -> Pose {
+        match self {
+            FrameConvention::YUpCounterClockwise => *pose,
+            FrameConvention::YDownClockwise => Pose {
+                x: pose.x,
+                y: -pose.y,
+                theta_radians: utils::normalize_angle_rad(-pose.theta_radians),
+            },
+        }
+    }
+
+    /// The mirror is its own inverse.
+    pub fn from_internal(&self, pose: &Pose) -> Pose {
+        self.to_internal(pose)
+    }
+
+    pub fn point_to_internal(&self, x: f64, y: f64) -> (f64, f64) {
+        match self {
+            FrameConvention::YUpCounterClockwise => (x, y),
+            FrameConvention::YDownClockwise => (x, -y),
+        }
+    }
+
+    pub fn point_from_internal(&self, x: f64, y: f64) -> (f64, f64) {
+        self.point_to_internal(x, y)
+    }
+
+    pub fn heading_to_internal(&self, heading: Heading) -> Heading {
+        match (self, heading) {
+            (FrameConvention::YUpCounterClockwise, _) | (_, Heading::Free) => heading,
+            (FrameConvention::YDownClockwise, Heading::Fixed(theta_degree)) => {
+                Heading::Fixed(utils::normalize_angle_degree(-theta_degree))
+            }
+            (
+                FrameConvention::YDownClockwise,
+                Heading::Interval {
+                    min_degree,
+                    max_degree,
+                },
+            ) => Heading::Interval {
+                min_degree: -max_degree,
+                max_degree: -min_degree,
+            },
+        }
+    }
+
+    /// Mirroring reverses the winding, so the vertex order is reversed to keep it.
+    pub fn polygon_to_internal(&self, polygon: &Polygon) -> Polygon {
+        match self {
+            FrameConvention::YUpCounterClockwise => polygon.clone(),
+            FrameConvention::YDownClockwise => Polygon::new(
+                polygon
+                    .vertices
+                    .iter()
+                    .rev()
+                    .map(|&(x, y)| self.point_to_internal(x, y))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn polygon_from_internal(&self, polygon: &Polygon) -> Polygon {
+        self.polygon_to_internal(polygon)
+    }
+
+    /// Rows are flipped so that row 0 of the image grid becomes the top row of the internal
+    /// grid.
+    pub fn grid_to_internal(&self, grid: &OccupancyGrid) -> OccupancyGrid {
+        match self {
+            FrameConvention::YUpCounterClockwise => grid.clone(),
+            FrameConvention::YDownClockwise => {
+                let mut cells = Vec::with_capacity(grid.cells.len());
+                for row in grid.cells.chunks(grid.width.max(1)).rev() {
+                    cells.extend_from_slice(row);
+                }
+                OccupancyGrid {
+                    origin_y: -(grid.origin_y + grid.height as f64 * grid.resolution),
+                    cells,
+                    ..grid.clone()
+                }
+            }
+        }
+    }
+
+    pub fn bounds_to_internal(&self, bounds: &Bounds) -> Bounds {
+        match self {
+            FrameConvention::YUpCounterClockwise => *bounds,
+            FrameConvention::YDownClockwise => {
+                Bounds::new(bounds.min_x, bounds.max_x, -bounds.max_y, -bounds.min_y)
+            }
+        }
+    }
+
+    pub fn region_to_internal(&self, region: &GoalRegion) -> GoalRegion {
+        let (x, y) = self.point_to_internal(region.x, region.y);
+        GoalRegion {
+            x,
+            y,
+            heading: self.heading_to_internal(region.heading),
+            ..*region
+        }
+    }
+
+    pub fn waypoint_to_internal(&self, waypoint: &Waypoint) -> Waypoint {
+        let (x, y) = self.point_to_internal(waypoint.x, waypoint.y);
+        Waypoint {
+            x,
+            y,
+            heading: self.heading_to_internal(waypoint.heading),
+        }
+    }
+
+    pub fn get_optimal_path(&self, start: Pose, end: Pose, radius: f64) -> Option<Path> {
+        get_optimal_path_for_radius(self.to_internal(&start), self.to_internal(&end), radius)
+    }
+
+    pub fn get_all_paths(&self, start: Pose, end: Pose, radius: f64) -> Vec<Path> {
+        get_all_paths(
+            utils::scale_pose(&self.to_internal(&start), 1. / radius),
+            utils::scale_pose(&self.to_internal(&end), 1. / radius),
+        )
+    }
+
+    pub fn end_pose(&self, start: Pose, path: &Path, radius: f64) -> Pose {
+        self.from_internal(&trajectory::end_pose(
+            self.to_internal(&start),
+            path,
+            radius,
+        ))
+    }
+
+    pub fn pose_at(&self, start: Pose, path: &Path, radius: f64, arc_length: f64) -> Pose {
+        self.from_internal(&trajectory::pose_at(
+            self.to_internal(&start),
+            path,
+            radius,
+            arc_length,
+        ))
+    }
+
+    pub fn sample(&self, start: Pose, path: &Path, radius: f64, step: f64) -> Vec<(f64, Pose)> {
+        trajectory::sample(self.to_internal(&start), path, radius, step)
+            .into_iter()
+            .map(|(arc_length, pose)| (arc_length, self.from_internal(&pose)))
+            .collect()
+    }
+
+    pub fn first_collision(
+        &self,
+        grid: &OccupancyGrid,
+        footprint: &Footprint,
+        start: Pose,
+        path: &Path,
+        radius: f64,
+    ) -> Option<f64> {
+        collision::first_collision(
+            &self.grid_to_internal(grid),
+            footprint,
+            self.to_internal(&start),
+            path,
+            radius,
+        )
+    }
+
+    pub fn path_clearance(
+        &self,
+        obstacles: &[Polygon],
+        footprint: &Polygon,
+        start: Pose,
+        path: &Path,
+        radius: f64,
+        tolerance: f64,
+    ) -> Clearance {
+        let obstacles: Vec<Polygon> = obstacles
+            .iter()
+            .map(|obstacle| self.polygon_to_internal(obstacle))
+            .collect();
+        let clearance = collision::path_clearance(
+            &obstacles,
+            &self.polygon_to_internal(footprint),
+            self.to_internal(&start),
+            path,
+            radius,
+            tolerance,
+        );
+        Clearance {
+            pose: self.from_internal(&clearance.pose),
+            ..clearance
+        }
+    }
+
+    pub fn swept_cover(
+        &self,
+        start: Pose,
+        path: &Path,
+        footprint: &Footprint,
+        radius: f64,
+        tolerance: f64,
+    ) -> SweptCover {
+        let cover =
+            swept::swept_cover(self.to_internal(&start), path, footprint, radius, tolerance);
+        SweptCover {
+            polygons: cover
+                .polygons
+                .iter()
+                .map(|polygon| self.polygon_from_internal(polygon))
+                .collect(),
+        }
+    }
+
+    pub fn hybrid_astar(
+        &self,
+        grid: &OccupancyGrid,
+        footprint: &Footprint,
+        start: Pose,
+        goal: Pose,
+        config: &HybridAStarConfig,
+    ) -> Option<Path> {
+        hybrid_astar::plan(
+            &self.grid_to_internal(grid),
+            footprint,
+            self.to_internal(&start),
+            self.to_internal(&goal),
+            config,
+        )
+    }
+
+    pub fn rrt_star<C>(
+        &self,
+        checker: &C,
+        bounds: &Bounds,
+        start: Pose,
+        goal: Pose,
+        config: &RrtStarConfig,
+    ) -> Option<Path>
+    where
+        C: CollisionChecker,
+    {
+        rrt_star::plan(
+            checker,
+            &self.bounds_to_internal(bounds),
+            self.to_internal(&start),
+            self.to_internal(&goal),
+            config,
+        )
+    }
+
+    /// The roadmap keeps its poses in the internal frame; query it through
+    /// [`FrameConvention::query_prm`].
+    pub fn build_prm<C>(&self, checker: &C, bounds: &Bounds, config: &PrmConfig) -> Prm
+    where
+        C: CollisionChecker,
+    {
+        Prm::build(checker, &self.bounds_to_internal(bounds), config)
+    }
+
+    pub fn query_prm<C>(&self, prm: &Prm, checker: &C, start: Pose, goal: Pose) -> Option<Path>
+    where
+        C: CollisionChecker,
+    {
+        prm.query(checker, self.to_internal(&start), self.to_internal(&goal))
+    }
+
+    pub fn get_optimal_path_to_region(
+        &self,
+        start: Pose,
+        region: &GoalRegion,
+        radius: f64,
+        samples: usize,
+    ) -> Option<(Pose, Path)> {
+        goal::get_optimal_path_to_region(
+            self.to_internal(&start),
+            &self.region_to_internal(region),
+            radius,
+            samples,
+        )
+        .map(|(goal, path)| (self.from_internal(&goal), path))
+    }
+
+    pub fn get_optimal_path_to_nearest_goal(
+        &self,
+        start: Pose,
+        goals: &[Pose],
+        radius: f64,
+    ) -> Option<(usize, Path)> {
+        let goals: Vec<Pose> = goals.iter().map(|goal| self.to_internal(goal)).collect();
+        goal::get_optimal_path_to_nearest_goal(self.to_internal(&start), &goals, radius)
+    }
+
+    pub fn plan_route(
+        &self,
+        waypoints: &[Waypoint],
+        radius: f64,
+        heading_samples: usize,
+    ) -> Option<Route> {
+        let waypoints: Vec<Waypoint> = waypoints
+            .iter()
+            .map(|waypoint| self.waypoint_to_internal(waypoint))
+            .collect();
+        let route = route::plan_route(&waypoints, radius, heading_samples)?;
+        Some(Route {
+            poses: route
+                .poses
+                .iter()
+                .map(|pose| self.from_internal(pose))
+                .collect(),
+            ..route
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sampling::Rng;
+
+    const IMAGE: FrameConvention = FrameConvention::YDownClockwise;
+
+    /// A 12 m × 12 m image grid with a wall across the middle rows, open on the right.
+    fn walled_grid() -> OccupancyGrid {
+        let mut grid = OccupancyGrid::new(0.5, 0., 0., 24, 24);
+        for col in 0..16 {
+            grid.set_occupied(col, 12, true);
+        }
+        grid
+    }
+
+    #[test]
+    fn image_round_trip_reaches_the_goal() {
+        let bounds = Bounds::new(-6., 6., -6., 6.);
+        let mut rng = Rng::new(5);
+        for _ in 0..50 {
+            let (start, goal) = (bounds.sample(&mut rng), bounds.sample(&mut rng));
+            let Some(path) = IMAGE.get_optimal_path(start, goal, 1.5) else {
+                continue;
+            };
+            let end = IMAGE.end_pose(start, &path, 1.5);
+            assert!((end.x - goal.x).abs() < 1e-9 && (end.y - goal.y).abs() < 1e-9);
+            assert!(
+                utils::normalize_angle_rad(end.theta_radians - goal.theta_radians).abs() < 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn left_turns_are_clockwise_in_image_coordinates() {
+        let path = IMAGE
+            .get_optimal_path(
+                Pose::from_degrees(0., 0., 0.),
+                Pose::from_degrees(1., -1., -90.),
+                1.,
+            )
+            .unwrap();
+        assert_eq!(path.len(), 1);
+        assert_eq!(path[0].steering, crate::Steering::Left);
+    }
+
+    #[test]
+    fn grids_are_mirrored_cell_by_cell() {
+        let grid = walled_grid();
+        let internal = IMAGE.grid_to_internal(&grid);
+        for row in 0..grid.height {
+            for col in 0..grid.width {
+                let (x, y) = (
+                    grid.origin_x + (col as f64 + 0.5) * grid.resolution,
+                    grid.origin_y + (row as f64 + 0.5) * grid.resolution,
+                );
+                assert_eq!(grid.is_occupied_at(x, y), internal.is_occupied_at(x, -y));
+            }
+        }
+    }
+
+    #[test]
+    fn heading_intervals_keep_their_headings() {
+        let region = GoalRegion {
+            x: 1.,
+            y: 2.,
+            tolerance: 0.5,
+            heading: Heading::Interval {
+                min_degree: 10.,
+                max_degree: 50.,
+            },
+        };
+        let internal = IMAGE.region_to_internal(&region);
+        for (theta_degree, inside) in [(10., true), (30., true), (50., true), (60., false)] {
+            let pose = IMAGE.to_internal(&Pose::from_degrees(1., 2., theta_degree));
+            assert_eq!(internal.contains(&pose), inside, "{theta_degree}");
+        }
+    }
+
+    #[test]
+    fn hybrid_astar_plans_around_an_image_wall() {
+        let grid = walled_grid();
+        let footprint = Footprint::new(0.6, 0.2, 0.5);
+        let start = Pose::from_degrees(2., 3., 0.);
+        let goal = Pose::from_degrees(2., 9., 180.);
+        let config = HybridAStarConfig::default();
+        let path = IMAGE
+            .hybrid_astar(&grid, &footprint, start, goal, &config)
+            .unwrap();
+
+        let end = IMAGE.end_pose(start, &path, config.radius);
+        assert!((end.x - goal.x).abs() < 1e-6 && (end.y - goal.y).abs() < 1e-6);
+        assert!(
+            IMAGE
+                .first_collision(&grid, &footprint, start, &path, config.radius)
+                .is_none()
+        );
+        assert!(
+            IMAGE
+                .sample(start, &path, config.radius, 0.1)
+                .iter()
+                .any(|(_, pose)| pose.x > 8.)
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use std::f64::consts::PI;
+
+use crate::nearest::lower_bound;
+use crate::route::Heading;
+use crate::utils;
+use crate::{Path, Pose, get_optimal_path_for_radius, path_length, reeds_shepp_distance};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoalRegion {
+    pub x: f64,
+    pub y: f64,
+    pub tolerance: f64,
+    pub heading: Heading,
+}
+
+impl GoalRegion {
+    pub fn contains(&self, pose: &Pose) -> bool {
+        (pose.x - self.x).hypot(pose.y - self.y) <= self.tolerance + 1e-9
+            && match self.heading {
+                Heading::Fixed(theta_degree) => {
+                    utils::normalize_angle_degree(pose.theta_degree() - theta_degree).abs() <= 1e-9
+                }
+                Heading::Interval { min_degree, .. } => {
+                    (pose.theta_degree() - min_degree).rem_euclid(360.)
+                        <= self.heading.span_degree() + 1e-9
+                }
+                Heading::Free => true,
+            }
+    }
+
+    /// Poses are parameterized by an offset from the center and a heading offset from the
+    /// start of the interval, clamped back into the region.
+    fn pose(&self, dx: f64, dy: f64, heading_offset: f64) -> Pose {
+        let distance = dx.hypot(dy);
+        let scale = if distance > self.tolerance {
+            self.tolerance / distance
+        } else {
+            1.
+        };
+        let theta_degree = match self.heading {
+            Heading::Fixed(theta_degree) => theta_degree,
+            Heading::Interval { min_degree, .. } => {
+                min_degree + heading_offset.clamp(0., self.heading.span_degree())
+            }
+            Heading::Free => heading_offset,
+        };
+        Pose::from_degrees(
+            self.x + dx * scale,
+            self.y + dy * scale,
+            utils::normalize_angle_degree(theta_degree),
+        )
+    }
+}
+
+/// The region is first sampled on rings around its center with `samples` headings, then the
+/// best sample is refined by a pattern search that stays inside the region.
+pub fn get_optimal_path_to_region(
+    start: Pose,
+    region: &GoalRegion,
+    radius: f64,
+    samples: usize,
+) -> Option<(Pose, Path)> {
+    if region.contains(&start) {
+        return Some((start, Vec::new()));
+    }
+
+    let cost = |(dx, dy, offset): (f64, f64, f64)| {
+        reeds_shepp_distance(start, region.pose(dx, dy, offset), radius)
+    };
+
+    let headings = region.heading.candidates(samples);
+    let offsets: Vec<f64> = match region.heading {
+        Heading::Interval { min_degree, .. } => headings
+            .iter()
+            .map(|h| (h - min_degree).rem_euclid(360.))
+            .collect(),
+        _ => headings,
+    };
+
+    let mut positions = vec![(0., 0.)];
+    for ring in 1..=2 {
+        let distance = region.tolerance * ring as f64 / 2.;
+        for i in 0..8 * ring {
+            let angle = 2. * PI * i as f64 / (8 * ring) as f64;
+            positions.push((distance * angle.cos(), distance * angle.sin()));
+        }
+    }
+
+    let mut best = positions
+        .iter()
+        .flat_map(|&(dx, dy)| offsets.iter().map(move |&offset| (dx, dy, offset)))
+        .map(|candidate| (candidate, cost(candidate)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    let mut position_step = region.tolerance / 4.;
+    let mut heading_step = region.heading.span_degree() / samples.max(1) as f64 / 2.;
+    for _ in 0..200 {
+        if position_step < 1e-6 && heading_step < 1e-6 {
+            break;
+        }
+        let ((dx, dy, offset), _) = best;
+        let moves = [
+            (dx + position_step, dy, offset),
+            (dx - position_step, dy, offset),
+            (dx, dy + position_step, offset),
+            (dx, dy - position_step, offset),
+            (dx, dy, offset + heading_step),
+            (dx, dy, offset - heading_step),
+        ];
+        let improved = moves
+            .into_iter()
+            .map(|candidate| (candidate, cost(candidate)))
+            .filter(|(_, c)| *c < best.1)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        match improved {
+            Some(candidate) => best = candidate,
+            None => {
+                position_step /= 2.;
+                heading_step /= 2.;
+            }
+        }
+    }
+
+    let ((dx, dy, offset), _) = best;
+    let goal = region.pose(dx, dy, offset);
+    let path = get_optimal_path_for_radius(start, goal, radius)?;
+    Some((goal, path))
+}
+
+/// Goals are evaluated in order of their lower bound and the search stops once no remaining
+/// goal can beat the best path found.
+pub fn get_optimal_path_to_nearest_goal(
+    start: Pose,
+    goals: &[Pose],
+    radius: f64,
+) -> Option<(usize, Path)> {
+    let mut order: Vec<(usize, f64)> = goals
+        .iter()
+        .map(|goal| lower_bound(start, *goal, radius))
+        .enumerate()
+        .collect();
+    order.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+
+    let mut best: Option<(usize, Path, f64)> = None;
+    for (index, bound) in order {
+        if best.as_ref().is_some_and(|(_, _, length)| bound >= *length) {
+            break;
+        }
+        if bound <= 1e-10 {
+            return Some((index, Vec::new()));
+        }
+        let Some(path) = get_optimal_path_for_radius(start, goals[index], radius) else {
+            continue;
+        };
+        let length = path_length(&path) * radius;
+        if best
+            .as_ref()
+            .is_none_or(|(_, _, best_length)| length < *best_length)
+        {
+            best = Some((index, path, length));
+        }
+    }
+    best.map(|(index, path, _)| (index, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Degrees;
+    use crate::sampling::{Bounds, Rng};
+
+    fn full_circle() -> GoalRegion {
+        GoalRegion {
+            x: 3.,
+            y: 0.,
+            tolerance: 0.5,
+            heading: Heading::Interval {
+                min_degree: -180.,
+                max_degree: 180.,
+            },
+        }
+    }
+
+    #[test]
+    fn full_interval_contains_every_heading() {
+        let region = full_circle();
+        for theta_degree in [-180., -90., 0., 90., 179.] {
+            assert!(region.contains(&Pose::new(3., 0., Degrees(theta_degree))));
+        }
+    }
+
+    #[test]
+    fn full_interval_matches_a_free_heading() {
+        let start = Pose::new(0., 0., Degrees(90.));
+        let free = GoalRegion {
+            heading: Heading::Free,
+            ..full_circle()
+        };
+        let (goal, path) = get_optimal_path_to_region(start, &full_circle(), 1., 8).unwrap();
+        let (_, free_path) = get_optimal_path_to_region(start, &free, 1., 8).unwrap();
+        assert!(full_circle().contains(&goal));
+        assert!((path_length(&path) - path_length(&free_path)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn nearest_goal_agrees_with_brute_force() {
+        let bounds = Bounds::new(-8., 8., -8., 8.);
+        let mut rng = Rng::new(11);
+        let radius = 1.5;
+        for _ in 0..30 {
+            let start = bounds.sample(&mut rng);
+            let goals: Vec<Pose> = (0..12).map(|_| bounds.sample(&mut rng)).collect();
+            let brute = goals
+                .iter()
+                .map(|goal| reeds_shepp_distance(start, *goal, radius))
+                .fold(f64::INFINITY, f64::min);
+
+            let (index, path) = get_optimal_path_to_nearest_goal(start, &goals, radius).unwrap();
+            assert!((path_length(&path) * radius - brute).abs() < 1e-9);
+            assert!((reeds_shepp_distance(start, goals[index], radius) - brute).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::f64::consts::PI;
+
+use crate::collision::{Footprint, OccupancyGrid, first_collision};
+use crate::trajectory;
+use crate::utils;
+use crate::{
+    Gear, Path, PathElement, Pose, Steering, concatenate, get_ranked_paths, path_length,
+    reeds_shepp_distance,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct HybridAStarConfig {
+    pub radius: f64,
+    pub xy_resolution: f64,
+    pub heading_bins: usize,
+    pub step_length: f64,
+    pub reverse_penalty: f64,
+    pub gear_switch_penalty: f64,
+    pub analytic_candidates: usize,
+    pub analytic_interval: usize,
+    pub max_iterations: usize,
+}
+
+impl Default for HybridAStarConfig {
+    fn default() -> Self {
+        HybridAStarConfig {
+            radius: 1.,
+            xy_resolution: 0.5,
+            heading_bins: 72,
+            step_length: 0.5,
+            reverse_penalty: 1.,
+            gear_switch_penalty: 0.,
+            analytic_candidates: 3,
+            analytic_interval: 5,
+            max_iterations: 100_000,
+        }
+    }
+}
+
+const PRIMITIVES: [(Steering, Gear); 6] = [
+    (Steering::Left, Gear::Forward),
+    (Steering::Straight, Gear::Forward),
+    (Steering::Right, Gear::Forward),
+    (Steering::Left, Gear::Backwards),
+    (Steering::Straight, Gear::Backwards),
+    (Steering::Right, Gear::Backwards),
+];
+
+struct Node {
+    pose: Pose,
+    cost: f64,
+    parent: Option<usize>,
+    element: Option<PathElement>,
+}
+
+struct Open {
+    priority: f64,
+    node: usize,
+}
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority.total_cmp(&other.priority) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.total_cmp(&self.priority)
+    }
+}
+
+pub fn plan(
+    grid: &OccupancyGrid,
+    footprint: &Footprint,
+    start: Pose,
+    goal: Pose,
+    config: &HybridAStarConfig,
+) -> Option<Path> {
+    let is_free = |pose: Pose, path: &Path| {
+        first_collision(grid, footprint, pose, path, config.radius).is_none()
+    };
+    if !is_free(start, &Vec::new()) || !is_free(goal, &Vec::new()) {
+        return None;
+    }
+
+    let holonomic = holonomic_distances(grid, goal)?;
+    let heuristic = |pose: &Pose| {
+        let cell = grid.cell_at(pose.x, pose.y)?;
+        let holonomic = holonomic[cell.1 * grid.width + cell.0];
+        let reeds_shepp = reeds_shepp_distance(*pose, goal, config.radius);
+        holonomic.is_finite().then_some(holonomic.max(reeds_shepp))
+    };
+
+    let mut nodes = vec![Node {
+        pose: start,
+        cost: 0.,
+        parent: None,
+        element: None,
+    }];
+    let mut best: HashMap<(i64, i64, usize), f64> = HashMap::new();
+    let mut open = BinaryHeap::new();
+    open.push(Open {
+        priority: heuristic(&start)?,
+        node: 0,
+    });
+
+    let step = config.step_length / config.radius;
+    for iteration in 0..config.max_iterations {
+        let Some(Open { node: current, .. }) = open.pop() else {
+            break;
+        };
+        let pose = nodes[current].pose;
+        if best
+            .get(&state_key(&pose, config))
+            .is_some_and(|&known| known < nodes[current].cost)
+        {
+            continue;
+        }
+
+        if iteration % config.analytic_interval.max(1) == 0
+            && let Some(tail) = analytic_expansion(&pose, goal, config, &is_free)
+        {
+            return Some(reconstruct(&nodes, current, tail));
+        }
+
+        for (steering, gear) in PRIMITIVES {
+            let element = PathElement {
+                param: step,
+                steering,
+                gear,
+            };
+            let motion = vec![element.clone()];
+            if !is_free(pose, &motion) {
+                continue;
+            }
+
+            let next = trajectory::end_pose(pose, &motion, config.radius);
+            let mut cost = nodes[current].cost + config.step_length;
+            if gear == Gear::Backwards {
+                cost += config.step_length * (config.reverse_penalty - 1.).max(0.);
+            }
+            if nodes[current]
+                .element
+                .as_ref()
+                .is_some_and(|e| e.gear != gear)
+            {
+                cost += config.gear_switch_penalty;
+            }
+
+            let key = state_key(&next, config);
+            if best.get(&key).is_some_and(|&known| known <= cost) {
+                continue;
+            }
+            let Some(h) = heuristic(&next) else {
+                continue;
+            };
+            best.insert(key, cost);
+
+            nodes.push(Node {
+                pose: next,
+                cost,
+                parent: Some(current),
+                element: Some(element),
+            });
+            open.push(Open {
+                priority: cost + h,
+                node: nodes.len() - 1,
+            });
+        }
+    }
+
+    None
+}
+
+fn state_key(pose: &Pose, config: &HybridAStarConfig) -> (i64, i64, usize) {
+    let heading = pose.theta_radians.rem_euclid(2. * PI);
+    let bin = (heading / (2. * PI) * config.heading_bins as f64) as usize;
+    (
+        (pose.x / config.xy_resolution).floor() as i64,
+        (pose.y / config.xy_resolution).floor() as i64,
+        bin % config.heading_bins.max(1),
+    )
+}
+
+fn analytic_expansion<F>(
+    pose: &Pose,
+    goal: Pose,
+    config: &HybridAStarConfig,
+    is_free: &F,
+) -> Option<Path>
+where
+    F: Fn(Pose, &Path) -> bool,
+{
+    get_ranked_paths(
+        utils::scale_pose(pose, 1. / config.radius),
+        utils::scale_pose(&goal, 1. / config.radius),
+        config.analytic_candidates,
+        path_length,
+    )
+    .into_iter()
+    .find(|path| is_free(*pose, path))
+}
+
+fn reconstruct(nodes: &[Node], last: usize, tail: Path) -> Path {
+    let mut elements = Vec::new();
+    let mut current = Some(last);
+    while let Some(index) = current {
+        if let Some(element) = &nodes[index].element {
+            elements.push(element.clone());
+        }
+        current = nodes[index].parent;
+    }
+    elements.reverse();
+    concatenate(vec![elements, tail])
+}
+
+/// Eight-connected Dijkstra from the goal cell over free cells, ignoring the heading.
+fn holonomic_distances(grid: &OccupancyGrid, goal: Pose) -> Option<Vec<f64>> {
+    let (goal_col, goal_row) = grid.cell_at(goal.x, goal.y)?;
+    let mut distances = vec![f64::INFINITY; grid.width * grid.height];
+    distances[goal_row * grid.width + goal_col] = 0.;
+
+    let mut open = BinaryHeap::new();
+    open.push(Open {
+        priority: 0.,
+        node: goal_row * grid.width + goal_col,
+    });
+    while let Some(Open { priority, node }) = open.pop() {
+        if priority > distances[node] {
+            continue;
+        }
+        let (col, row) = ((node % grid.width) as i64, (node / grid.width) as i64);
+        for (dc, dr) in [
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ] {
+            let (c, r) = (col + dc, row + dr);
+            if c < 0 || r < 0 || c >= grid.width as i64 || r >= grid.height as i64 {
+                continue;
+            }
+            let neighbour = r as usize * grid.width + c as usize;
+            if grid.cells[neighbour] {
+                continue;
+            }
+            let distance = priority + grid.resolution * ((dc * dc + dr * dr) as f64).sqrt();
+            if distance < distances[neighbour] {
+                distances[neighbour] = distance;
+                open.push(Open {
+                    priority: distance,
+                    node: neighbour,
+                });
+            }
+        }
+    }
+    Some(distances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Degrees;
+
+    /// A 12 m × 12 m grid with a wall across the middle rows, open on the right.
+    fn walled_grid() -> OccupancyGrid {
+        let mut grid = OccupancyGrid::new(0.5, 0., 0., 24, 24);
+        for col in 0..16 {
+            grid.set_occupied(col, 12, true);
+        }
+        grid
+    }
+
+    #[test]
+    fn plans_around_a_wall_to_the_goal() {
+        let grid = walled_grid();
+        let footprint = Footprint::new(0.6, 0.2, 0.5);
+        let config = HybridAStarConfig {
+            radius: 1.5,
+            ..Default::default()
+        };
+        let start = Pose::new(3., 3., Degrees(0.));
+        let goal = Pose::new(3., 9., Degrees(180.));
+
+        let path = plan(&grid, &footprint, start, goal, &config).unwrap();
+        let end = trajectory::end_pose(start, &path, config.radius);
+        assert!((end.x - goal.x).abs() < 1e-6 && (end.y - goal.y).abs() < 1e-6);
+        assert!(utils::normalize_angle_rad(end.theta_radians - goal.theta_radians).abs() < 1e-6);
+        assert!(first_collision(&grid, &footprint, start, &path, config.radius).is_none());
+    }
+
+    #[test]
+    fn start_in_collision_has_no_plan() {
+        let grid = walled_grid();
+        let footprint = Footprint::new(0.6, 0.2, 0.5);
+        let start = Pose::new(3., 6.2, Degrees(0.));
+        let goal = Pose::new(3., 9., Degrees(180.));
+        assert!(
+            plan(
+                &grid,
+                &footprint,
+                start,
+                goal,
+                &HybridAStarConfig::default()
+            )
+            .is_none()
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use crate::trajectory;
+use crate::utils;
+use crate::{Gear, Path, Steering};
+
+/// Records carry raw values so callers can localize them; `Display` renders the English text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    Drive {
+        gear: Gear,
+        steering: Steering,
+        distance: f64,
+        turn_degree: f64,
+    },
+    Stop {
+        shift_to: Gear,
+    },
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Instruction::Drive {
+                gear,
+                steering,
+                distance,
+                turn_degree,
+            } => {
+                let direction = match gear {
+                    Gear::Forward => "forward",
+                    Gear::Backwards => "reverse",
+                };
+                match steering {
+                    Steering::Straight => write!(f, "{direction} straight {distance:.1} m"),
+                    Steering::Left => write!(
+                        f,
+                        "{direction}, full left, {distance:.1} m ({turn_degree:.0}°)"
+                    ),
+                    Steering::Right => write!(
+                        f,
+                        "{direction}, full right, {distance:.1} m ({turn_degree:.0}°)"
+                    ),
+                }
+            }
+            Instruction::Stop { shift_to } => match shift_to {
+                Gear::Forward => write!(f, "stop, shift to forward"),
+                Gear::Backwards => write!(f, "stop, shift to reverse"),
+            },
+        }
+    }
+}
+
+/// Distances are in meters for a path normalized to `radius` meters. Turn angles are the
+/// heading change of each arc in degrees, and a stop is inserted at every cusp.
+pub fn instructions(path: &Path, radius: f64) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    for (i, e) in path.iter().enumerate() {
+        if i > 0 && path[i - 1].gear != e.gear {
+            instructions.push(Instruction::Stop { shift_to: e.gear });
+        }
+        let turn_degree = match e.steering {
+            Steering::Straight => 0.,
+            Steering::Left | Steering::Right => utils::radians_to_degree(e.param.abs()),
+        };
+        instructions.push(Instruction::Drive {
+            gear: e.gear,
+            steering: e.steering,
+            distance: trajectory::element_length(e, radius),
+            turn_degree,
+        });
+    }
+    instructions
+}
+
+pub fn render(path: &Path, radius: f64) -> String {
+    instructions(path, radius)
+        .iter()
+        .map(|instruction| format!("{instruction}\n"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::PathElement;
+
+    #[test]
+    fn renders_a_stop_at_the_cusp() {
+        let path = vec![
+            PathElement::create(FRAC_PI_2, Steering::Left, Gear::Forward),
+            PathElement::create(1.5, Steering::Straight, Gear::Forward),
+            PathElement::create(-FRAC_PI_2 / 3., Steering::Right, Gear::Forward),
+        ];
+        assert_eq!(
+            render(&path, 2.),
+            "forward, full left, 3.1 m (90°)\n\
+             forward straight 3.0 m\n\
+             stop, shift to reverse\n\
+             reverse, full right, 1.0 m (30°)\n"
+        );
+    }
+}
@@ -1,8 +1,33 @@
 use std::f64::consts::PI;
 
+pub mod asymmetric;
+pub mod collision;
+pub mod comfort;
+pub mod constraints;
+pub mod docking;
+pub mod frame;
+pub mod goal;
+pub mod hybrid_astar;
+pub mod instructions;
+pub mod nearest;
+pub mod notation;
+pub mod polygon;
+pub mod prm;
+pub mod ranking;
+pub mod replan;
+pub mod route;
+pub mod rrt_star;
+pub mod sampling;
+pub mod steering;
+pub mod swept;
+pub mod trajectory;
 pub mod utils;
+pub mod vehicle;
+pub use ranking::{Tolerance, get_near_optimal_paths, get_ranked_paths};
+pub use replan::{Replan, replan};
 pub use utils::Pose;
 pub use utils::normalize_angle_rad;
+pub use utils::{Degrees, Radians, Se2};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Steering {
@@ -80,40 +105,69 @@ pub fn get_optimal_path(start: Pose, end: Pose) -> Option<Path> {
 }
 
 pub type PathFn = fn(f64, f64, f64) -> Path;
-pub const PATH_FNS: [PathFn; 12] = [
+
+/// The families take the goal heading in radians.
+pub const PATH_FNS_RADIANS: [PathFn; 12] = [
     path1, path2, path3, path4, path5, path6, path7, path8, path9, path10, path11, path12,
 ];
 
+/// The same families taking the goal heading in degrees.
+pub const PATH_FNS: [PathFn; 12] = [
+    |x, y, phi_degree| path1(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path2(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path3(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path4(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path5(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path6(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path7(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path8(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path9(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path10(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path11(x, y, utils::degree_to_radians(phi_degree)),
+    |x, y, phi_degree| path12(x, y, utils::degree_to_radians(phi_degree)),
+];
+
 pub fn get_all_paths(start: Pose, end: Pose) -> Vec<Path> {
-    let mut paths: Vec<Path> = Vec::new();
-
-    let pose = utils::change_of_basis(&start, &end);
-    let x = pose.x;
-    let y = pose.y;
-    let theta_degree = pose.theta_degree;
-    for get_path in PATH_FNS {
-        let p1 = get_path(x, y, theta_degree);
-        let p2 = timeflip(get_path(-x, y, -theta_degree));
-        let p3 = reflect(get_path(x, -y, -theta_degree));
-        let p4 = reflect(timeflip(get_path(-x, -y, theta_degree)));
-
-        paths.push(p1);
-        paths.push(p2);
-        paths.push(p3);
-        paths.push(p4);
-    }
+    get_family_paths(start, end)
+        .into_iter()
+        .map(|(_, path)| path)
+        .collect()
+}
 
-    paths
+pub(crate) fn get_family_paths(start: Pose, end: Pose) -> Vec<(usize, Path)> {
+    get_raw_family_paths(start, end)
         .into_iter()
-        .map(|path| {
-            path.into_iter()
-                .filter(|e| e.param.abs() > 1e-10)
-                .collect::<Path>()
+        .map(|(family, path)| {
+            (
+                family,
+                path.into_iter()
+                    .filter(|e| e.param.abs() > 1e-10)
+                    .collect::<Path>(),
+            )
         })
-        .filter(|path| !path.is_empty())
+        .filter(|(_, path)| !path.is_empty())
         .collect()
 }
 
+pub(crate) fn get_raw_family_paths(start: Pose, end: Pose) -> Vec<(usize, Path)> {
+    let mut paths: Vec<(usize, Path)> = Vec::new();
+
+    let (x, y, theta) = utils::change_of_basis_radians(&start, &end);
+    for (family, get_path) in PATH_FNS_RADIANS.iter().enumerate() {
+        let p1 = get_path(x, y, theta);
+        let p2 = timeflip(get_path(-x, y, -theta));
+        let p3 = reflect(get_path(x, -y, -theta));
+        let p4 = reflect(timeflip(get_path(-x, -y, theta)));
+
+        paths.push((family, p1));
+        paths.push((family, p2));
+        paths.push((family, p3));
+        paths.push((family, p4));
+    }
+
+    paths
+}
+
 pub fn timeflip(path: Path) -> Path {
     path.into_iter()
         .map(|mut e| {
@@ -132,12 +186,45 @@ pub fn reflect(path: Path) -> Path {
         .collect()
 }
 
+/// The same motion driven from its end back to its start.
+pub fn reverse(path: &Path) -> Path {
+    timeflip(path.iter().rev().cloned().collect())
+}
+
 pub fn path_length(path: &Path) -> f64 {
     path.iter().map(|e| e.param.abs()).sum()
 }
 
-fn path1(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
+pub fn get_optimal_path_for_radius(start: Pose, end: Pose, radius: f64) -> Option<Path> {
+    get_optimal_path(
+        utils::scale_pose(&start, 1. / radius),
+        utils::scale_pose(&end, 1. / radius),
+    )
+}
+
+pub fn reeds_shepp_distance(start: Pose, end: Pose, radius: f64) -> f64 {
+    get_optimal_path_for_radius(start, end, radius)
+        .map_or(f64::INFINITY, |path| path_length(&path) * radius)
+}
+
+pub fn concatenate(paths: Vec<Path>) -> Path {
+    let mut joined: Path = Vec::new();
+    for element in paths.into_iter().flatten() {
+        match joined.last_mut() {
+            Some(last) if last.steering == element.steering && last.gear == element.gear => {
+                last.param += element.param;
+            }
+            _ => joined.push(element),
+        }
+    }
+    joined
+}
+
+pub fn cusps(path: &Path) -> usize {
+    path.windows(2).filter(|w| w[0].gear != w[1].gear).count()
+}
+
+fn path1(x: f64, y: f64, phi_radians: f64) -> Path {
     let polar = utils::cartesian_to_polar(x - phi_radians.sin(), y - 1. + phi_radians.cos());
     let v = utils::normalize_angle_rad(phi_radians - polar.theta);
 
@@ -148,8 +235,8 @@ fn path1(x: f64, y: f64, phi_degree: f64) -> Path {
     ]
 }
 
-fn path2(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::normalize_angle_rad(utils::degree_to_radians(phi_degree));
+fn path2(x: f64, y: f64, phi_radians: f64) -> Path {
+    let phi_radians = utils::normalize_angle_rad(phi_radians);
     let polar = utils::cartesian_to_polar(x + phi_radians.sin(), y - 1. - phi_radians.cos());
 
     let rho = polar.rho;
@@ -170,9 +257,7 @@ fn path2(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path3(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path3(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x - phi_radians.sin();
     let eta = y - 1. + phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -196,9 +281,7 @@ fn path3(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path4(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path4(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x - phi_radians.sin();
     let eta = y - 1. + phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -222,9 +305,7 @@ fn path4(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path5(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path5(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x - phi_radians.sin();
     let eta = y - 1. + phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -248,9 +329,7 @@ fn path5(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path6(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path6(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x + phi_radians.sin();
     let eta = y - 1. - phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -283,9 +362,7 @@ fn path6(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path7(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path7(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x + phi_radians.sin();
     let eta = y - 1. - phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -313,9 +390,7 @@ fn path7(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path8(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path8(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x - phi_radians.sin();
     let eta = y - 1. + phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -347,9 +422,7 @@ fn path8(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path9(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path9(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x - phi_radians.sin();
     let eta = y - 1. + phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -381,9 +454,7 @@ fn path9(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path10(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path10(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x + phi_radians.sin();
     let eta = y - 1. - phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -407,9 +478,7 @@ fn path10(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path11(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path11(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x + phi_radians.sin();
     let eta = y - 1. - phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
@@ -433,9 +502,7 @@ fn path11(x: f64, y: f64, phi_degree: f64) -> Path {
     }
 }
 
-fn path12(x: f64, y: f64, phi_degree: f64) -> Path {
-    let phi_radians = utils::degree_to_radians(phi_degree);
-
+fn path12(x: f64, y: f64, phi_radians: f64) -> Path {
     let xi = x + phi_radians.sin();
     let eta = y - 1. - phi_radians.cos();
     let polar = utils::cartesian_to_polar(xi, eta);
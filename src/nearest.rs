@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use crate::utils;
+use crate::{Pose, path_length, path1, reeds_shepp_distance};
+
+#[derive(Debug, Clone)]
+pub struct PoseIndex {
+    pub radius: f64,
+    pub cell_size: f64,
+    poses: Vec<Pose>,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    min_cell: (i64, i64),
+    max_cell: (i64, i64),
+}
+
+impl PoseIndex {
+    pub fn new(radius: f64, cell_size: f64) -> Self {
+        PoseIndex {
+            radius,
+            cell_size,
+            poses: Vec::new(),
+            cells: HashMap::new(),
+            min_cell: (i64::MAX, i64::MAX),
+            max_cell: (i64::MIN, i64::MIN),
+        }
+    }
+
+    pub fn insert(&mut self, pose: Pose) -> usize {
+        let index = self.poses.len();
+        let cell = self.cell_of(&pose);
+        self.cells.entry(cell).or_default().push(index);
+        self.min_cell = (self.min_cell.0.min(cell.0), self.min_cell.1.min(cell.1));
+        self.max_cell = (self.max_cell.0.max(cell.0), self.max_cell.1.max(cell.1));
+        self.poses.push(pose);
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.poses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.poses.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Pose> {
+        self.poses.get(index)
+    }
+
+    pub fn poses(&self) -> &[Pose] {
+        &self.poses
+    }
+
+    /// Candidates are gathered ring by ring until the Euclidean distance to the next ring,
+    /// a lower bound on the Reeds-Shepp distance, exceeds the k-th smallest upper bound seen
+    /// so far. Only candidates whose lower bound stays below that threshold are evaluated
+    /// exactly.
+    pub fn nearest(&self, query: Pose, k: usize) -> Vec<(usize, f64)> {
+        if k == 0 || self.is_empty() {
+            return Vec::new();
+        }
+
+        let center = self.cell_of(&query);
+        let mut candidates: Vec<(usize, f64)> = Vec::new();
+        let mut upper_bounds: Vec<f64> = Vec::new();
+        let mut threshold = f64::INFINITY;
+
+        for ring in 0..=self.max_ring(center) {
+            if (ring as f64 - 1.) * self.cell_size > threshold {
+                break;
+            }
+            for index in self.ring(center, ring) {
+                let pose = self.poses[index];
+                let lower = euclidean(&query, &pose);
+                if lower > threshold {
+                    continue;
+                }
+                candidates.push((index, lower));
+                upper_bounds.push(upper_bound(query, pose, self.radius));
+            }
+            if upper_bounds.len() >= k {
+                upper_bounds.sort_by(f64::total_cmp);
+                upper_bounds.truncate(k);
+                threshold = upper_bounds[k - 1];
+            }
+        }
+
+        candidates.retain(|&(_, lower)| lower <= threshold);
+        candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let mut found: Vec<(usize, f64)> = Vec::with_capacity(k + 1);
+        for (index, lower) in candidates {
+            if found.len() == k && lower >= found[k - 1].1 {
+                break;
+            }
+            let distance = reeds_shepp_distance(query, self.poses[index], self.radius);
+            let position = found.partition_point(|&(_, d)| d <= distance);
+            found.insert(position, (index, distance));
+            found.truncate(k);
+        }
+        found
+    }
+
+    pub fn within(&self, query: Pose, max_distance: f64) -> Vec<(usize, f64)> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        let center = self.cell_of(&query);
+        let rings = ((max_distance / self.cell_size).ceil().max(0.) as usize)
+            .saturating_add(1)
+            .min(self.max_ring(center));
+
+        let mut found: Vec<(usize, f64)> = (0..=rings)
+            .flat_map(|ring| self.ring(center, ring))
+            .filter(|&index| euclidean(&query, &self.poses[index]) <= max_distance)
+            .map(|index| {
+                (
+                    index,
+                    reeds_shepp_distance(query, self.poses[index], self.radius),
+                )
+            })
+            .filter(|&(_, distance)| distance <= max_distance)
+            .collect();
+        found.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        found
+    }
+
+    fn cell_of(&self, pose: &Pose) -> (i64, i64) {
+        (
+            (pose.x / self.cell_size).floor() as i64,
+            (pose.y / self.cell_size).floor() as i64,
+        )
+    }
+
+    fn max_ring(&self, center: (i64, i64)) -> usize {
+        [
+            center.0 - self.min_cell.0,
+            self.max_cell.0 - center.0,
+            center.1 - self.min_cell.1,
+            self.max_cell.1 - center.1,
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+        .max(0) as usize
+    }
+
+    fn ring(&self, center: (i64, i64), ring: usize) -> impl Iterator<Item = usize> + '_ {
+        let ring = ring as i64;
+        let horizontal = (-ring..=ring).flat_map(move |dx| [(dx, -ring), (dx, ring)]);
+        let vertical = (1 - ring..ring).flat_map(move |dy| [(-ring, dy), (ring, dy)]);
+        let mut offsets: Vec<(i64, i64)> = horizontal.chain(vertical).collect();
+        offsets.dedup();
+        offsets
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.cells.get(&(center.0 + dx, center.1 + dy)))
+            .flatten()
+            .copied()
+    }
+}
+
+pub fn euclidean(a: &Pose, b: &Pose) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Only arcs change the heading, so a path must also turn through at least the heading
+/// difference.
+pub fn lower_bound(start: Pose, end: Pose, radius: f64) -> f64 {
+    let turn = utils::normalize_angle_rad(end.theta_radians - start.theta_radians).abs();
+    euclidean(&start, &end).max(turn * radius)
+}
+
+/// Any feasible path bounds the optimum from above; the CSC family of `path1` exists for
+/// every pair of poses and costs a single evaluation per symmetry.
+pub fn upper_bound(start: Pose, end: Pose, radius: f64) -> f64 {
+    let (x, y, theta) = utils::change_of_basis_radians(
+        &utils::scale_pose(&start, 1. / radius),
+        &utils::scale_pose(&end, 1. / radius),
+    );
+    [
+        path1(x, y, theta),
+        path1(-x, y, -theta),
+        path1(x, -y, -theta),
+        path1(-x, -y, theta),
+    ]
+    .iter()
+    .map(path_length)
+    .fold(f64::INFINITY, f64::min)
+        * radius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sampling::{Bounds, Rng};
+
+    const RADIUS: f64 = 1.5;
+
+    fn seeded_index(rng: &mut Rng, bounds: &Bounds, count: usize) -> PoseIndex {
+        let mut index = PoseIndex::new(RADIUS, 2.);
+        for _ in 0..count {
+            index.insert(bounds.sample(rng));
+        }
+        index
+    }
+
+    fn brute_force(index: &PoseIndex, query: Pose) -> Vec<(usize, f64)> {
+        let mut all: Vec<(usize, f64)> = index
+            .poses()
+            .iter()
+            .enumerate()
+            .map(|(i, pose)| (i, reeds_shepp_distance(query, *pose, RADIUS)))
+            .collect();
+        all.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        all
+    }
+
+    fn distances(found: &[(usize, f64)]) -> Vec<f64> {
+        found.iter().map(|&(_, distance)| distance).collect()
+    }
+
+    #[test]
+    fn nearest_agrees_with_brute_force() {
+        let bounds = Bounds::new(-10., 10., -10., 10.);
+        let mut rng = Rng::new(3);
+        let index = seeded_index(&mut rng, &bounds, 200);
+        for k in [1, 5, 20] {
+            for _ in 0..20 {
+                let query = bounds.sample(&mut rng);
+                let expected = brute_force(&index, query);
+                let found = index.nearest(query, k);
+                assert_eq!(distances(&found), distances(&expected[..k]));
+            }
+        }
+    }
+
+    #[test]
+    fn within_agrees_with_brute_force() {
+        let bounds = Bounds::new(-10., 10., -10., 10.);
+        let mut rng = Rng::new(4);
+        let index = seeded_index(&mut rng, &bounds, 200);
+        for max_distance in [0.5, 3., 8.] {
+            for _ in 0..20 {
+                let query = bounds.sample(&mut rng);
+                let expected: Vec<(usize, f64)> = brute_force(&index, query)
+                    .into_iter()
+                    .filter(|&(_, distance)| distance <= max_distance)
+                    .collect();
+                assert_eq!(index.within(query, max_distance), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn bounds_bracket_the_reeds_shepp_distance() {
+        let bounds = Bounds::new(-10., 10., -10., 10.);
+        let mut rng = Rng::new(5);
+        for _ in 0..500 {
+            let (start, end) = (bounds.sample(&mut rng), bounds.sample(&mut rng));
+            let distance = reeds_shepp_distance(start, end, RADIUS);
+            assert!(lower_bound(start, end, RADIUS) <= distance + 1e-9);
+            assert!(distance <= upper_bound(start, end, RADIUS) + 1e-9);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Gear, Path, PathElement, Steering};
+
+/// Elements are written as a steering letter followed by the signed parameter, the sign
+/// giving the gear: `L+1.57 S+2.00 R-0.78`. Parameters use the formatter precision, two
+/// decimals by default, so `{:.6}` writes fixtures that round-trip to six decimals.
+impl fmt::Display for PathElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = match self.steering {
+            Steering::Left => 'L',
+            Steering::Right => 'R',
+            Steering::Straight => 'S',
+        };
+        let sign = match self.gear {
+            Gear::Forward => '+',
+            Gear::Backwards => '-',
+        };
+        let precision = f.precision().unwrap_or(2);
+        write!(f, "{letter}{sign}{:.*}", precision, self.param.abs())
+    }
+}
+
+/// `Path` is a plain `Vec`, so it is displayed through this wrapper.
+#[derive(Debug, Clone, Copy)]
+pub struct Notation<'a>(pub &'a Path);
+
+impl fmt::Display for Notation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        for (i, e) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{e:.precision$}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn notation(path: &Path) -> Notation<'_> {
+    Notation(path)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    Steering { token: String },
+    Sign { token: String },
+    Param { token: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Steering { token } => {
+                write!(f, "`{token}` does not start with L, S or R")
+            }
+            ParseError::Sign { token } => {
+                write!(f, "`{token}` has no + or - after the steering letter")
+            }
+            ParseError::Param { token } => {
+                write!(f, "`{token}` has no valid unsigned parameter")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl FromStr for PathElement {
+    type Err = ParseError;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        let mut chars = token.chars();
+        let steering = match chars.next() {
+            Some('L') => Steering::Left,
+            Some('R') => Steering::Right,
+            Some('S') => Steering::Straight,
+            _ => {
+                return Err(ParseError::Steering {
+                    token: token.to_string(),
+                });
+            }
+        };
+        let gear = match chars.next() {
+            Some('+') => Gear::Forward,
+            Some('-') => Gear::Backwards,
+            _ => {
+                return Err(ParseError::Sign {
+                    token: token.to_string(),
+                });
+            }
+        };
+        let digits = chars.as_str();
+        let param = digits
+            .starts_with(|c: char| c.is_ascii_digit() || c == '.')
+            .then(|| digits.parse::<f64>().ok())
+            .flatten()
+            .filter(|param| param.is_finite())
+            .ok_or_else(|| ParseError::Param {
+                token: token.to_string(),
+            })?;
+        Ok(PathElement {
+            param,
+            steering,
+            gear,
+        })
+    }
+}
+
+/// Elements are separated by whitespace; an empty string is the empty path.
+pub fn parse_path(text: &str) -> Result<Path, ParseError> {
+    text.split_whitespace().map(str::parse).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_optimal_path_for_radius;
+    use crate::sampling::{Bounds, Rng};
+
+    #[test]
+    fn optimal_paths_round_trip_to_the_written_precision() {
+        let bounds = Bounds::new(-5., 5., -5., 5.);
+        let mut rng = Rng::new(9);
+        for _ in 0..100 {
+            let (start, goal) = (bounds.sample(&mut rng), bounds.sample(&mut rng));
+            let Some(path) = get_optimal_path_for_radius(start, goal, 1.) else {
+                continue;
+            };
+            let parsed = parse_path(&format!("{:.6}", notation(&path))).unwrap();
+            assert_eq!(parsed.len(), path.len());
+            for (a, b) in path.iter().zip(&parsed) {
+                assert_eq!((a.steering, a.gear), (b.steering, b.gear));
+                assert!((a.param - b.param).abs() <= 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn writes_the_compact_form() {
+        let path = parse_path("L+1.57 S+2 R-0.785").unwrap();
+        assert_eq!(notation(&path).to_string(), "L+1.57 S+2.00 R-0.79");
+        assert!(parse_path("  ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn malformed_tokens_are_rejected() {
+        let error = |token: &str| parse_path(token).unwrap_err();
+        assert!(matches!(error("X+1"), ParseError::Steering { .. }));
+        assert!(matches!(error("+1"), ParseError::Steering { .. }));
+        assert!(matches!(error("L1.0"), ParseError::Sign { .. }));
+        assert!(matches!(error("L"), ParseError::Sign { .. }));
+        assert!(matches!(error("L++1.0"), ParseError::Param { .. }));
+        assert!(matches!(error("L+-1.0"), ParseError::Param { .. }));
+        assert!(matches!(error("L+"), ParseError::Param { .. }));
+        assert!(matches!(error("L+inf"), ParseError::Param { .. }));
+        assert!(matches!(error("S+1.0 R-x"), ParseError::Param { .. }));
+    }
+}
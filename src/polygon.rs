@@ -0,0 +1,275 @@
+use crate::Pose;
+use crate::utils;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    pub vertices: Vec<(f64, f64)>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<(f64, f64)>) -> Self {
+        Polygon { vertices }
+    }
+
+    pub fn rectangle(front: f64, rear: f64, width: f64) -> Self {
+        let half = width / 2.;
+        Polygon::new(vec![
+            (front, half),
+            (-rear, half),
+            (-rear, -half),
+            (front, -half),
+        ])
+    }
+
+    pub fn convex_hull(points: &[(f64, f64)]) -> Self {
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        points.dedup();
+        if points.len() < 3 {
+            return Polygon::new(points);
+        }
+
+        let mut hull: Vec<(f64, f64)> = Vec::with_capacity(points.len() * 2);
+        for pass in [points.clone(), points.into_iter().rev().collect()] {
+            let floor = hull.len();
+            for p in pass {
+                while hull.len() >= floor + 2
+                    && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.
+                {
+                    hull.pop();
+                }
+                hull.push(p);
+            }
+            hull.pop();
+        }
+        Polygon::new(hull)
+    }
+
+    pub fn transformed(&self, pose: &Pose) -> Polygon {
+        Polygon::new(
+            self.vertices
+                .iter()
+                .map(|&(x, y)| utils::local_to_world(pose, x, y))
+                .collect(),
+        )
+    }
+
+    pub fn bounding_radius(&self) -> f64 {
+        self.vertices
+            .iter()
+            .map(|&(x, y)| x.hypot(y))
+            .fold(0., f64::max)
+    }
+
+    pub fn area(&self) -> f64 {
+        self.edges()
+            .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+            .sum::<f64>()
+            / 2.
+    }
+
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let mut inside = false;
+        for ((x1, y1), (x2, y2)) in self.edges() {
+            if (y1 > y) != (y2 > y) && x < x1 + (y - y1) * (x2 - x1) / (y2 - y1) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Positive values are the exact distance between separated polygons, negative values
+    /// a penetration depth found by the separating axis test.
+    ///
+    /// The separating axis test only holds for convex polygons, so concave ones are split
+    /// into convex pieces with [`Polygon::convex_pieces`] and the smallest clearance between
+    /// pieces is returned. Distances stay exact; penetration depths of concave polygons are
+    /// those of the deepest overlapping pieces.
+    pub fn clearance(&self, other: &Polygon) -> f64 {
+        if self.is_convex() && other.is_convex() {
+            return self.convex_clearance(other);
+        }
+        let others = other.convex_pieces();
+        self.convex_pieces()
+            .iter()
+            .flat_map(|a| others.iter().map(move |b| a.convex_clearance(b)))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let mut sign = 0.;
+        for i in 0..n {
+            let cross = cross(
+                self.vertices[i],
+                self.vertices[(i + 1) % n],
+                self.vertices[(i + 2) % n],
+            );
+            if cross.abs() <= 1e-12 {
+                continue;
+            }
+            if sign * cross < 0. {
+                return false;
+            }
+            sign = cross;
+        }
+        true
+    }
+
+    /// Splits a simple polygon into convex pieces: the polygon itself when it is convex,
+    /// otherwise the triangles found by ear clipping. Self-intersecting polygons have no
+    /// such split and fall back to their convex hull.
+    pub fn convex_pieces(&self) -> Vec<Polygon> {
+        if self.is_convex() {
+            return vec![self.clone()];
+        }
+
+        let mut remaining = self.vertices.clone();
+        if self.area() < 0. {
+            remaining.reverse();
+        }
+        let mut triangles = Vec::with_capacity(remaining.len().saturating_sub(2));
+        while remaining.len() > 3 {
+            let n = remaining.len();
+            let ear = (0..n).find(|&i| {
+                let (a, b, c) = (
+                    remaining[(i + n - 1) % n],
+                    remaining[i],
+                    remaining[(i + 1) % n],
+                );
+                let triangle = Polygon::new(vec![a, b, c]);
+                cross(a, b, c) > 1e-12
+                    && remaining
+                        .iter()
+                        .filter(|&&p| p != a && p != b && p != c)
+                        .all(|&(x, y)| !triangle.contains(x, y))
+            });
+            let Some(i) = ear else {
+                return vec![Polygon::convex_hull(&self.vertices)];
+            };
+            triangles.push(Polygon::new(vec![
+                remaining[(i + n - 1) % n],
+                remaining[i],
+                remaining[(i + 1) % n],
+            ]));
+            remaining.remove(i);
+        }
+        triangles.push(Polygon::new(remaining));
+        triangles
+    }
+
+    pub fn intersects(&self, other: &Polygon) -> bool {
+        self.clearance(other) <= 0.
+    }
+
+    fn convex_clearance(&self, other: &Polygon) -> f64 {
+        let separation = self.axis_separation(other).max(other.axis_separation(self));
+        if separation <= 0. {
+            return separation;
+        }
+
+        let a_to_b = self.vertices.iter().flat_map(|&p| {
+            other
+                .edges()
+                .map(move |(a, b)| point_segment_distance(p, a, b))
+        });
+        let b_to_a = other.vertices.iter().flat_map(|&p| {
+            self.edges()
+                .map(move |(a, b)| point_segment_distance(p, a, b))
+        });
+        a_to_b.chain(b_to_a).fold(f64::INFINITY, f64::min)
+    }
+
+    fn axis_separation(&self, other: &Polygon) -> f64 {
+        self.edges()
+            .filter_map(|((x1, y1), (x2, y2))| {
+                let length = (x2 - x1).hypot(y2 - y1);
+                (length > 0.).then(|| ((y2 - y1) / length, (x1 - x2) / length))
+            })
+            .map(|axis| {
+                let (min_a, max_a) = project(&self.vertices, axis);
+                let (min_b, max_b) = project(&other.vertices, axis);
+                (min_b - max_a).max(min_a - max_b)
+            })
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+}
+
+fn cross(o: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+fn project(vertices: &[(f64, f64)], (ax, ay): (f64, f64)) -> (f64, f64) {
+    vertices
+        .iter()
+        .map(|&(x, y)| x * ax + y * ay)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        })
+}
+
+pub fn point_segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0. {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length_sq).clamp(0., 1.)
+    } else {
+        0.
+    };
+    (p.0 - a.0 - t * dx).hypot(p.1 - a.1 - t * dy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A U shape open towards +y, with a notch between x = 1 and x = 2 above y = 1.
+    fn u_shape() -> Polygon {
+        Polygon::new(vec![
+            (0., 0.),
+            (3., 0.),
+            (3., 3.),
+            (2., 3.),
+            (2., 1.),
+            (1., 1.),
+            (1., 3.),
+            (0., 3.),
+        ])
+    }
+
+    fn square(x: f64, y: f64, half: f64) -> Polygon {
+        Polygon::new(vec![
+            (x - half, y - half),
+            (x + half, y - half),
+            (x + half, y + half),
+            (x - half, y + half),
+        ])
+    }
+
+    #[test]
+    fn convexity() {
+        assert!(square(0., 0., 1.).is_convex());
+        assert!(!u_shape().is_convex());
+    }
+
+    #[test]
+    fn concave_obstacles_are_split_into_pieces() {
+        let pieces = u_shape().convex_pieces();
+        assert!(pieces.iter().all(Polygon::is_convex));
+        let area: f64 = pieces.iter().map(|p| p.area().abs()).sum();
+        assert!((area - u_shape().area().abs()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn clearance_inside_a_concave_notch() {
+        let inside = square(1.5, 2., 0.25);
+        assert!((u_shape().clearance(&inside) - 0.25).abs() < 1e-9);
+        assert!((inside.clearance(&u_shape()) - 0.25).abs() < 1e-9);
+        assert!(u_shape().intersects(&square(1.5, 1., 0.25)));
+    }
+}
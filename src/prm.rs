@@ -0,0 +1,279 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::collision::CollisionChecker;
+use crate::nearest::PoseIndex;
+use crate::sampling::{Bounds, Rng, connection_radius};
+use crate::{Path, Pose, concatenate, get_optimal_path_for_radius, reeds_shepp_distance, reverse};
+
+#[derive(Debug, Clone, Copy)]
+pub struct PrmConfig {
+    pub radius: f64,
+    pub samples: usize,
+    pub max_attempts: usize,
+    pub connection_gamma: Option<f64>,
+    pub seed: u64,
+}
+
+impl Default for PrmConfig {
+    fn default() -> Self {
+        PrmConfig {
+            radius: 1.,
+            samples: 500,
+            max_attempts: 10_000,
+            connection_gamma: None,
+            seed: 0,
+        }
+    }
+}
+
+/// Each edge keeps the collision-checked path from its node to `to`. The optimal path between
+/// two poses is not unique, so the reverse direction stores that same path driven backwards
+/// rather than planning it again.
+#[derive(Debug, Clone)]
+pub struct Edge {
+    pub to: usize,
+    pub cost: f64,
+    pub path: Path,
+}
+
+#[derive(Debug, Clone)]
+pub struct Prm {
+    pub radius: f64,
+    pub connection_radius: f64,
+    pub poses: Vec<Pose>,
+    pub edges: Vec<Vec<Edge>>,
+}
+
+impl Prm {
+    pub fn build<C>(checker: &C, bounds: &Bounds, config: &PrmConfig) -> Self
+    where
+        C: CollisionChecker,
+    {
+        let mut rng = Rng::new(config.seed);
+        let mut poses = Vec::with_capacity(config.samples);
+        for _ in 0..config.max_attempts {
+            if poses.len() >= config.samples {
+                break;
+            }
+            let pose = bounds.sample(&mut rng);
+            if checker.is_free(pose, &Vec::new(), config.radius) {
+                poses.push(pose);
+            }
+        }
+
+        let gamma = config
+            .connection_gamma
+            .unwrap_or_else(|| bounds.default_gamma(config.radius));
+        let mut prm = Prm {
+            radius: config.radius,
+            connection_radius: connection_radius(gamma, poses.len()),
+            edges: vec![Vec::new(); poses.len()],
+            poses,
+        };
+
+        let mut index = PoseIndex::new(prm.radius, prm.connection_radius);
+        for a in 0..prm.poses.len() {
+            for (b, cost) in index.within(prm.poses[a], prm.connection_radius) {
+                if let Some(path) = prm.connect(checker, prm.poses[b], prm.poses[a]) {
+                    add_edge(&mut prm.edges, b, a, cost, path);
+                }
+            }
+            index.insert(prm.poses[a]);
+        }
+        prm
+    }
+
+    pub fn query<C>(&self, checker: &C, start: Pose, goal: Pose) -> Option<Path>
+    where
+        C: CollisionChecker,
+    {
+        let start_index = self.poses.len();
+        let goal_index = start_index + 1;
+        let mut graph = Overlay {
+            edges: &self.edges,
+            extra: HashMap::new(),
+        };
+
+        let mut index = PoseIndex::new(self.radius, self.connection_radius);
+        for &pose in &self.poses {
+            index.insert(pose);
+        }
+        for (other, cost) in index.within(start, self.connection_radius) {
+            if let Some(path) = self.connect(checker, start, self.poses[other]) {
+                graph.add_edge(start_index, other, cost, path);
+            }
+        }
+        for (other, cost) in index.within(goal, self.connection_radius) {
+            if let Some(path) = self.connect(checker, self.poses[other], goal) {
+                graph.add_edge(other, goal_index, cost, path);
+            }
+        }
+        let direct = reeds_shepp_distance(start, goal, self.radius);
+        if direct <= self.connection_radius
+            && let Some(path) = self.connect(checker, start, goal)
+        {
+            graph.add_edge(start_index, goal_index, direct, path);
+        }
+
+        let route = shortest_route(&graph, start_index, goal_index)?;
+        Some(concatenate(
+            route.into_iter().map(|edge| edge.path.clone()).collect(),
+        ))
+    }
+
+    fn connect<C>(&self, checker: &C, from: Pose, to: Pose) -> Option<Path>
+    where
+        C: CollisionChecker,
+    {
+        get_optimal_path_for_radius(from, to, self.radius)
+            .filter(|path| checker.is_free(from, path, self.radius))
+    }
+}
+
+fn add_edge(edges: &mut [Vec<Edge>], from: usize, to: usize, cost: f64, path: Path) {
+    edges[to].push(Edge {
+        to: from,
+        cost,
+        path: reverse(&path),
+    });
+    edges[from].push(Edge { to, cost, path });
+}
+
+/// The roadmap plus the edges of a single query. The start and goal are the two nodes after
+/// the roadmap's own, and their edges live in `extra` so the roadmap is never copied.
+struct Overlay<'a> {
+    edges: &'a [Vec<Edge>],
+    extra: HashMap<usize, Vec<Edge>>,
+}
+
+impl Overlay<'_> {
+    fn len(&self) -> usize {
+        self.edges.len() + 2
+    }
+
+    fn neighbours(&self, node: usize) -> impl Iterator<Item = &Edge> {
+        self.edges
+            .get(node)
+            .into_iter()
+            .chain(self.extra.get(&node))
+            .flatten()
+    }
+
+    fn add_edge(&mut self, from: usize, to: usize, cost: f64, path: Path) {
+        self.extra.entry(to).or_default().push(Edge {
+            to: from,
+            cost,
+            path: reverse(&path),
+        });
+        self.extra
+            .entry(from)
+            .or_default()
+            .push(Edge { to, cost, path });
+    }
+}
+
+struct Open {
+    cost: f64,
+    node: usize,
+}
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost.total_cmp(&other.cost) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+/// The route is returned as the edge taken at every hop.
+fn shortest_route<'a>(graph: &'a Overlay, start: usize, goal: usize) -> Option<Vec<&'a Edge>> {
+    let mut costs = vec![f64::INFINITY; graph.len()];
+    let mut parents: Vec<Option<(usize, &Edge)>> = vec![None; graph.len()];
+    let mut open = BinaryHeap::new();
+    costs[start] = 0.;
+    open.push(Open {
+        cost: 0.,
+        node: start,
+    });
+
+    while let Some(Open { cost, node }) = open.pop() {
+        if node == goal {
+            break;
+        }
+        if cost > costs[node] {
+            continue;
+        }
+        for edge in graph.neighbours(node) {
+            if cost + edge.cost < costs[edge.to] {
+                costs[edge.to] = cost + edge.cost;
+                parents[edge.to] = Some((node, edge));
+                open.push(Open {
+                    cost: cost + edge.cost,
+                    node: edge.to,
+                });
+            }
+        }
+    }
+
+    if costs[goal].is_infinite() {
+        return None;
+    }
+    let mut route = Vec::new();
+    let mut node = goal;
+    while let Some((parent, edge)) = parents[node] {
+        route.push(edge);
+        node = parent;
+    }
+    route.reverse();
+    Some(route)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Degrees, trajectory};
+
+    fn stays_right_of(start: Pose, path: &Path, radius: f64) -> bool {
+        trajectory::sample(start, path, radius, 0.05)
+            .iter()
+            .all(|(_, pose)| pose.x > -0.5)
+    }
+
+    #[test]
+    fn edges_are_traversed_along_the_checked_path() {
+        let (a, b) = (
+            Pose::new(0., 0., Degrees(0.)),
+            Pose::new(0., 2., Degrees(180.)),
+        );
+        let path = get_optimal_path_for_radius(a, b, 1.).unwrap();
+        assert!(stays_right_of(a, &path, 1.));
+        let mut edges = vec![Vec::new(), Vec::new()];
+        add_edge(&mut edges, 0, 1, trajectory::total_length(&path, 1.), path);
+        let prm = Prm {
+            radius: 1.,
+            connection_radius: 1.,
+            poses: vec![a, b],
+            edges,
+        };
+
+        let start = Pose::new(0.5, 2., Degrees(180.));
+        let goal = Pose::new(0.5, 0., Degrees(0.));
+        let route = prm.query(&stays_right_of, start, goal).unwrap();
+        assert!(stays_right_of(start, &route, 1.));
+        let end = trajectory::end_pose(start, &route, 1.);
+        assert!((end.x - goal.x).abs() < 1e-9 && (end.y - goal.y).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Gear, Path, PathElement, Pose, cusps, get_all_paths, get_family_paths, path_length};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    Absolute(f64),
+    Relative(f64),
+}
+
+impl Tolerance {
+    fn threshold(&self, optimum: f64) -> f64 {
+        match *self {
+            Tolerance::Absolute(tolerance) => optimum + tolerance.max(0.),
+            Tolerance::Relative(tolerance) => optimum * (1. + tolerance.max(0.)),
+        }
+    }
+}
+
+/// Lengths closer than this are considered equal when ordering near-optimal paths.
+const LENGTH_EPSILON: f64 = 1e-9;
+
+/// Returns every candidate whose length is within `tolerance` of the optimum, shortest first.
+///
+/// Paths of numerically equal length are ordered by fewer cusps first, then paths starting
+/// in `Gear::Forward`, then their family index in `PATH_FNS`. Several families produce the
+/// same path; only its first occurrence in that order is kept.
+pub fn get_near_optimal_paths(start: Pose, end: Pose, tolerance: Tolerance) -> Vec<Path> {
+    let paths = get_family_paths(start, end);
+
+    let Some(optimum) = paths
+        .iter()
+        .map(|(_, path)| path_length(path))
+        .min_by(f64::total_cmp)
+    else {
+        return Vec::new();
+    };
+    let threshold = tolerance.threshold(optimum);
+
+    let mut near: Vec<(f64, usize, Path)> = paths
+        .into_iter()
+        .map(|(family, path)| (path_length(&path), family, path))
+        .filter(|(length, _, _)| *length <= threshold)
+        .collect();
+    near.sort_by(|(a, _, _), (b, _, _)| a.total_cmp(b));
+
+    let mut ordered = Vec::with_capacity(near.len());
+    let mut rest = near.as_mut_slice();
+    while let Some(&(shortest, _, _)) = rest.first() {
+        let ties = rest
+            .iter()
+            .position(|(length, _, _)| *length - shortest > LENGTH_EPSILON)
+            .unwrap_or(rest.len());
+        let (group, tail) = rest.split_at_mut(ties);
+        group.sort_by_key(|(_, family, path)| (cusps(path), starts_backwards(path), *family));
+        ordered.extend(group.iter().map(|(_, _, path)| path.clone()));
+        rest = tail;
+    }
+
+    let mut unique: Vec<Path> = Vec::new();
+    for path in ordered {
+        if !unique.iter().any(|other| same_path(other, &path)) {
+            unique.push(path);
+        }
+    }
+    unique
+}
+
+/// Returns the `k` cheapest distinct candidates under `cost_fn`, cheapest first.
+///
+/// Candidates with a NaN cost are ordered after every other candidate.
+pub fn get_ranked_paths<F>(start: Pose, end: Pose, k: usize, cost_fn: F) -> Vec<Path>
+where
+    F: Fn(&Path) -> f64,
+{
+    let mut unique: Vec<Path> = Vec::new();
+    for path in get_all_paths(start, end) {
+        if !unique.iter().any(|other| same_path(other, &path)) {
+            unique.push(path);
+        }
+    }
+
+    let mut ranked: Vec<(f64, Path)> = unique
+        .into_iter()
+        .map(|path| (cost_fn(&path), path))
+        .collect();
+    ranked.sort_by(|(a, _), (b, _)| a.is_nan().cmp(&b.is_nan()).then(a.total_cmp(b)));
+
+    ranked.into_iter().take(k).map(|(_, path)| path).collect()
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_element(a, b))
+}
+
+fn same_element(a: &PathElement, b: &PathElement) -> bool {
+    a.steering == b.steering && a.gear == b.gear && (a.param - b.param).abs() <= 1e-10
+}
+
+fn starts_backwards(path: &Path) -> bool {
+    path.first().is_some_and(|e| e.gear == Gear::Backwards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Degrees;
+
+    #[test]
+    fn near_optimal_paths_are_distinct() {
+        let paths = get_near_optimal_paths(
+            Pose::new(0., 0., Degrees(0.)),
+            Pose::new(0., 2., Degrees(180.)),
+            Tolerance::Relative(0.01),
+        );
+        assert_eq!(paths.len(), 4);
+        for (i, a) in paths.iter().enumerate() {
+            assert!(paths[i + 1..].iter().all(|b| !same_path(a, b)));
+        }
+    }
+
+    #[test]
+    fn near_optimal_paths_are_sorted_by_length() {
+        let paths = get_near_optimal_paths(
+            Pose::new(0., 0., Degrees(0.)),
+            Pose::new(3., 1., Degrees(60.)),
+            Tolerance::Relative(0.5),
+        );
+        assert!(paths.len() > 1);
+        for pair in paths.windows(2) {
+            let (a, b) = (path_length(&pair[0]), path_length(&pair[1]));
+            assert!(a <= b + LENGTH_EPSILON);
+            if (a - b).abs() <= LENGTH_EPSILON {
+                assert!(cusps(&pair[0]) <= cusps(&pair[1]));
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::utils;
+use crate::{Path, PathElement, Pose, get_all_paths, path_length};
+
+#[derive(Debug, Clone)]
+pub struct Replan {
+    pub path: Path,
+    pub kept_previous: bool,
+    pub kept_segments: usize,
+    pub kept_length: f64,
+}
+
+/// Keeps the previous word unless the optimum is more than `margin` shorter. As with
+/// `get_optimal_path_for_radius`, the returned path is normalised to a unit radius, while
+/// `margin` and `kept_length` are in world units.
+pub fn replan(
+    current: Pose,
+    goal: Pose,
+    previous: &Path,
+    margin: f64,
+    radius: f64,
+) -> Option<Replan> {
+    let paths = get_all_paths(
+        utils::scale_pose(&current, 1. / radius),
+        utils::scale_pose(&goal, 1. / radius),
+    );
+
+    let optimal = paths
+        .iter()
+        .min_by(|a, b| path_length(a).total_cmp(&path_length(b)))?;
+    let optimal_length = path_length(optimal);
+
+    let continuation = paths
+        .iter()
+        .filter(|path| follows_word(path, previous))
+        .min_by(|a, b| path_length(a).total_cmp(&path_length(b)));
+
+    match continuation {
+        Some(path) if path_length(path) <= optimal_length + margin.max(0.) / radius => {
+            Some(Replan {
+                path: path.clone(),
+                kept_previous: true,
+                kept_segments: path.len(),
+                kept_length: path_length(path) * radius,
+            })
+        }
+        _ => Some(Replan {
+            path: optimal.clone(),
+            kept_previous: false,
+            kept_segments: 0,
+            kept_length: 0.,
+        }),
+    }
+}
+
+fn follows_word(path: &Path, previous: &Path) -> bool {
+    !path.is_empty()
+        && path.len() <= previous.len()
+        && path
+            .iter()
+            .zip(&previous[previous.len() - path.len()..])
+            .all(|(a, b)| same_letter(a, b))
+}
+
+fn same_letter(a: &PathElement, b: &PathElement) -> bool {
+    a.steering == b.steering && a.gear == b.gear
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Degrees, reeds_shepp_distance};
+
+    #[test]
+    fn previous_word_is_kept_only_within_the_margin() {
+        let (current, goal) = (
+            Pose::new(0., 0., Degrees(0.)),
+            Pose::new(5., 1., Degrees(30.)),
+        );
+        let radius = 2.;
+        let optimum = reeds_shepp_distance(current, goal, radius);
+
+        // A previous word that the optimum does not follow, kept at any margin.
+        let (previous, kept) = get_all_paths(
+            utils::scale_pose(&current, 1. / radius),
+            utils::scale_pose(&goal, 1. / radius),
+        )
+        .into_iter()
+        .map(|path| {
+            let kept = replan(current, goal, &path, f64::INFINITY, radius).unwrap();
+            (path, kept)
+        })
+        .find(|(_, kept)| kept.kept_length > optimum + 1e-3)
+        .unwrap();
+        assert!(kept.kept_previous);
+        assert!(follows_word(&kept.path, &previous));
+        assert!((path_length(&kept.path) * radius - kept.kept_length).abs() < 1e-9);
+        let extra = kept.kept_length - optimum;
+
+        let within = replan(current, goal, &previous, extra + 1e-6, radius).unwrap();
+        assert!(within.kept_previous);
+        assert!((within.kept_length - kept.kept_length).abs() < 1e-9);
+
+        let beyond = replan(current, goal, &previous, extra - 1e-6, radius).unwrap();
+        assert!(!beyond.kept_previous);
+        assert_eq!(beyond.kept_segments, 0);
+        assert!((path_length(&beyond.path) * radius - optimum).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::utils;
+use crate::{Path, Pose, get_optimal_path_for_radius, reeds_shepp_distance};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Heading {
+    Fixed(f64),
+    Interval { min_degree: f64, max_degree: f64 },
+    Free,
+}
+
+impl Heading {
+    /// Intervals are closed and run counter-clockwise from `min_degree` to `max_degree`, so
+    /// they may wrap across ±180°. An interval spanning 360° or more covers the whole circle.
+    pub fn span_degree(&self) -> f64 {
+        match *self {
+            Heading::Fixed(_) => 0.,
+            Heading::Interval {
+                min_degree,
+                max_degree,
+            } => {
+                if max_degree - min_degree >= 360. {
+                    360.
+                } else {
+                    (max_degree - min_degree).rem_euclid(360.)
+                }
+            }
+            Heading::Free => 360.,
+        }
+    }
+
+    /// Whole circles are sampled without repeating the heading where they close.
+    pub fn candidates(&self, samples: usize) -> Vec<f64> {
+        let samples = samples.max(1);
+        let span = self.span_degree();
+        match *self {
+            Heading::Fixed(theta_degree) => vec![theta_degree],
+            Heading::Interval { min_degree, .. } if span < 360. => {
+                if samples == 1 {
+                    return vec![utils::normalize_angle_degree(min_degree + span / 2.)];
+                }
+                (0..samples)
+                    .map(|i| {
+                        utils::normalize_angle_degree(
+                            min_degree + span * i as f64 / (samples - 1) as f64,
+                        )
+                    })
+                    .collect()
+            }
+            Heading::Interval { min_degree, .. } => (0..samples)
+                .map(|i| {
+                    utils::normalize_angle_degree(min_degree + 360. * i as f64 / samples as f64)
+                })
+                .collect(),
+            Heading::Free => (0..samples)
+                .map(|i| -180. + 360. * i as f64 / samples as f64)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Waypoint {
+    pub x: f64,
+    pub y: f64,
+    pub heading: Heading,
+}
+
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub poses: Vec<Pose>,
+    pub legs: Vec<Path>,
+    pub length: f64,
+}
+
+/// Headings are chosen jointly by dynamic programming over `heading_samples` candidates
+/// per waypoint, so the total length is minimal over the discretization.
+pub fn plan_route(waypoints: &[Waypoint], radius: f64, heading_samples: usize) -> Option<Route> {
+    let layers: Vec<Vec<Pose>> = waypoints
+        .iter()
+        .map(|waypoint| {
+            waypoint
+                .heading
+                .candidates(heading_samples)
+                .into_iter()
+                .map(|theta_degree| Pose::from_degrees(waypoint.x, waypoint.y, theta_degree))
+                .collect()
+        })
+        .collect();
+    let first = layers.first()?;
+
+    let mut costs: Vec<Vec<f64>> = vec![vec![0.; first.len()]];
+    let mut parents: Vec<Vec<usize>> = vec![vec![0; first.len()]];
+    for pair in layers.windows(2) {
+        let previous_costs = costs.last()?;
+        let mut layer_costs = Vec::with_capacity(pair[1].len());
+        let mut layer_parents = Vec::with_capacity(pair[1].len());
+        for to in &pair[1] {
+            let (parent, cost) = pair[0]
+                .iter()
+                .zip(previous_costs)
+                .map(|(from, cost)| cost + reeds_shepp_distance(*from, *to, radius))
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(&b.1))?;
+            layer_costs.push(cost);
+            layer_parents.push(parent);
+        }
+        costs.push(layer_costs);
+        parents.push(layer_parents);
+    }
+
+    let (mut choice, &length) = costs
+        .last()?
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(b.1))?;
+    if !length.is_finite() {
+        return None;
+    }
+
+    let mut poses = Vec::with_capacity(layers.len());
+    for (layer, layer_parents) in layers.iter().zip(&parents).rev() {
+        poses.push(layer[choice]);
+        choice = layer_parents[choice];
+    }
+    poses.reverse();
+
+    let legs = poses
+        .windows(2)
+        .map(|pair| get_optimal_path_for_radius(pair[0], pair[1], radius))
+        .collect::<Option<Vec<Path>>>()?;
+
+    Some(Route {
+        poses,
+        legs,
+        length,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_interval_covers_the_circle() {
+        let heading = Heading::Interval {
+            min_degree: -180.,
+            max_degree: 180.,
+        };
+        assert_eq!(heading.span_degree(), 360.);
+        assert_eq!(heading.candidates(8), Heading::Free.candidates(8));
+    }
+
+    #[test]
+    fn intervals_wrap_across_180() {
+        let heading = Heading::Interval {
+            min_degree: 170.,
+            max_degree: -170.,
+        };
+        assert!((heading.span_degree() - 20.).abs() < 1e-12);
+        let candidates = heading.candidates(3);
+        assert!((candidates[0] - 170.).abs() < 1e-9);
+        assert!((candidates[1] + 180.).abs() < 1e-9);
+        assert!((candidates[2] + 170.).abs() < 1e-9);
+    }
+}
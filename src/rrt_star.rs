@@ -0,0 +1,217 @@
+use crate::collision::CollisionChecker;
+use crate::nearest::{PoseIndex, lower_bound};
+use crate::sampling::{Bounds, Rng, connection_radius};
+use crate::trajectory;
+use crate::{Path, Pose, concatenate, get_optimal_path_for_radius, reeds_shepp_distance};
+
+#[derive(Debug, Clone, Copy)]
+pub struct RrtStarConfig {
+    pub radius: f64,
+    pub max_iterations: usize,
+    pub max_step: f64,
+    pub goal_bias: f64,
+    pub rewire_gamma: Option<f64>,
+    pub seed: u64,
+}
+
+impl Default for RrtStarConfig {
+    fn default() -> Self {
+        RrtStarConfig {
+            radius: 1.,
+            max_iterations: 2_000,
+            max_step: 3.,
+            goal_bias: 0.05,
+            rewire_gamma: None,
+            seed: 0,
+        }
+    }
+}
+
+struct Node {
+    pose: Pose,
+    cost: f64,
+    parent: Option<usize>,
+    edge: Path,
+    children: Vec<usize>,
+}
+
+pub fn plan<C>(
+    checker: &C,
+    bounds: &Bounds,
+    start: Pose,
+    goal: Pose,
+    config: &RrtStarConfig,
+) -> Option<Path>
+where
+    C: CollisionChecker,
+{
+    let radius = config.radius;
+    let gamma = config
+        .rewire_gamma
+        .unwrap_or_else(|| bounds.default_gamma(radius));
+    let mut rng = Rng::new(config.seed);
+
+    let mut nodes = vec![Node {
+        pose: start,
+        cost: 0.,
+        parent: None,
+        edge: Vec::new(),
+        children: Vec::new(),
+    }];
+    let mut index = PoseIndex::new(radius, config.max_step);
+    index.insert(start);
+    let mut goal_parents: Vec<(usize, Path)> = Vec::new();
+    if let Some(to_goal) = connect_to_goal(checker, start, goal, config) {
+        goal_parents.push((0, to_goal));
+    }
+
+    for _ in 0..config.max_iterations {
+        let target = if rng.next_f64() < config.goal_bias {
+            goal
+        } else {
+            bounds.sample(&mut rng)
+        };
+
+        let nearest = index.nearest(target, 1).first()?.0;
+        let Some(towards) = get_optimal_path_for_radius(nodes[nearest].pose, target, radius) else {
+            continue;
+        };
+        let steered = trajectory::truncate(&towards, radius, config.max_step);
+        if steered.is_empty() || !checker.is_free(nodes[nearest].pose, &steered, radius) {
+            continue;
+        }
+        let new_pose = trajectory::end_pose(nodes[nearest].pose, &steered, radius);
+
+        let reach = connection_radius(gamma, nodes.len() + 1).min(config.max_step);
+        let mut near = index.within(new_pose, reach);
+        near.sort_by(|a, b| (nodes[a.0].cost + a.1).total_cmp(&(nodes[b.0].cost + b.1)));
+
+        let mut parent = nearest;
+        let mut edge = steered;
+        let mut cost = nodes[nearest].cost + trajectory::total_length(&edge, radius);
+        for &(i, d) in &near {
+            if nodes[i].cost + d >= cost {
+                break;
+            }
+            if let Some(candidate) = get_optimal_path_for_radius(nodes[i].pose, new_pose, radius)
+                && checker.is_free(nodes[i].pose, &candidate, radius)
+            {
+                parent = i;
+                edge = candidate;
+                cost = nodes[i].cost + d;
+                break;
+            }
+        }
+
+        let new = index.insert(new_pose);
+        nodes.push(Node {
+            pose: new_pose,
+            cost,
+            parent: Some(parent),
+            edge,
+            children: Vec::new(),
+        });
+        nodes[parent].children.push(new);
+
+        for &(i, d) in &near {
+            if i == parent || cost + d >= nodes[i].cost {
+                continue;
+            }
+            if let Some(candidate) = get_optimal_path_for_radius(new_pose, nodes[i].pose, radius)
+                && checker.is_free(new_pose, &candidate, radius)
+            {
+                if let Some(old_parent) = nodes[i].parent {
+                    nodes[old_parent].children.retain(|&child| child != i);
+                }
+                nodes[new].children.push(i);
+                nodes[i].parent = Some(new);
+                nodes[i].edge = candidate;
+                let delta = cost + d - nodes[i].cost;
+                propagate_cost(&mut nodes, i, delta);
+            }
+        }
+
+        if let Some(to_goal) = connect_to_goal(checker, new_pose, goal, config) {
+            goal_parents.push((new, to_goal));
+        }
+    }
+
+    let (last, to_goal) = goal_parents.into_iter().min_by(|(a, pa), (b, pb)| {
+        let cost_a = nodes[*a].cost + trajectory::total_length(pa, radius);
+        let cost_b = nodes[*b].cost + trajectory::total_length(pb, radius);
+        cost_a.total_cmp(&cost_b)
+    })?;
+
+    let mut edges = vec![to_goal];
+    let mut current = last;
+    while let Some(parent) = nodes[current].parent {
+        edges.push(nodes[current].edge.clone());
+        current = parent;
+    }
+    edges.reverse();
+    Some(concatenate(edges))
+}
+
+/// A pose that already lies on the goal connects with an empty path, since no planner call
+/// returns a path between coinciding poses.
+fn connect_to_goal<C>(checker: &C, pose: Pose, goal: Pose, config: &RrtStarConfig) -> Option<Path>
+where
+    C: CollisionChecker,
+{
+    let radius = config.radius;
+    if lower_bound(pose, goal, radius) <= 1e-9 {
+        return Some(Vec::new());
+    }
+    if reeds_shepp_distance(pose, goal, radius) > config.max_step {
+        return None;
+    }
+    get_optimal_path_for_radius(pose, goal, radius)
+        .filter(|path| checker.is_free(pose, path, radius))
+}
+
+fn propagate_cost(nodes: &mut [Node], root: usize, delta: f64) {
+    let mut stack = vec![root];
+    while let Some(index) = stack.pop() {
+        nodes[index].cost += delta;
+        stack.extend(nodes[index].children.iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn free(_: Pose, _: &Path, _: f64) -> bool {
+        true
+    }
+
+    #[test]
+    fn steering_onto_the_goal_finishes_the_path() {
+        let bounds = Bounds::new(-5., 5., -5., 5.);
+        let start = Pose::new(0., 0., crate::Degrees(0.));
+        let goal = Pose::new(2., 0., crate::Degrees(0.));
+        for max_iterations in [1, 5, 20, 50] {
+            let config = RrtStarConfig {
+                goal_bias: 1.,
+                max_iterations,
+                ..RrtStarConfig::default()
+            };
+            let path = plan(&free, &bounds, start, goal, &config).unwrap();
+            assert!((trajectory::total_length(&path, 1.) - 2.).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn start_on_the_goal_gives_an_empty_path() {
+        let bounds = Bounds::new(-5., 5., -5., 5.);
+        let start = Pose::new(1., 1., crate::Degrees(30.));
+        let config = RrtStarConfig {
+            max_iterations: 0,
+            ..RrtStarConfig::default()
+        };
+        assert_eq!(
+            plan(&free, &bounds, start, start, &config).unwrap().len(),
+            0
+        );
+    }
+}
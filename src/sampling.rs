@@ -0,0 +1,79 @@
+use std::f64::consts::PI;
+
+use crate::Pose;
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// SplitMix64, so equal seeds give equal sequences on every platform.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds {
+    pub min_x: f64,
+    pub max_x: f64,
+    pub min_y: f64,
+    pub max_y: f64,
+}
+
+impl Bounds {
+    pub fn new(min_x: f64, max_x: f64, min_y: f64, max_y: f64) -> Self {
+        Bounds {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
+
+    pub fn sample(&self, rng: &mut Rng) -> Pose {
+        Pose {
+            x: rng.range(self.min_x, self.max_x),
+            y: rng.range(self.min_y, self.max_y),
+            theta_radians: rng.range(-PI, PI),
+        }
+    }
+
+    pub fn area(&self) -> f64 {
+        (self.max_x - self.min_x) * (self.max_y - self.min_y)
+    }
+
+    /// Small Reeds-Shepp balls grow with the fourth power of their radius rather than the
+    /// third, so the RRT*/PRM* radius uses dimension 4. Headings are weighted by the turning
+    /// radius to give the configuration space volume a length scale.
+    pub fn default_gamma(&self, radius: f64) -> f64 {
+        let volume = self.area() * 2. * PI * radius;
+        let unit_ball = PI * PI / 2.;
+        2. * ((1. + 1. / REEDS_SHEPP_DIMENSION) * volume / unit_ball)
+            .powf(1. / REEDS_SHEPP_DIMENSION)
+    }
+}
+
+pub const REEDS_SHEPP_DIMENSION: f64 = 4.;
+
+pub fn connection_radius(gamma: f64, count: usize) -> f64 {
+    let n = count.max(2) as f64;
+    gamma * (n.ln() / n).powf(1. / REEDS_SHEPP_DIMENSION)
+}
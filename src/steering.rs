@@ -0,0 +1,147 @@
+use crate::Path;
+use crate::vehicle::VehicleModel;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteeringLimits {
+    pub max_rate_degree: f64,
+    pub speed: f64,
+    pub max_swing_distance: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    pub index: usize,
+    pub from_degree: f64,
+    pub to_degree: f64,
+    pub swing_time: f64,
+    pub swing_distance: f64,
+    pub at_cusp: bool,
+    pub requires_stop: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteeringReport {
+    pub transitions: Vec<Transition>,
+    pub stationary_time: f64,
+}
+
+impl SteeringReport {
+    pub fn is_feasible(&self) -> bool {
+        self.transitions.iter().all(|t| !t.requires_stop)
+    }
+
+    pub fn infeasible(&self) -> impl Iterator<Item = &Transition> {
+        self.transitions.iter().filter(|t| t.requires_stop)
+    }
+}
+
+/// Every change of steering between consecutive elements is a transition, `index` being the
+/// element it leads into. Cusps stop the vehicle anyway, so their wheels are repositioned at
+/// standstill. Elsewhere the wheels swing while rolling at `speed`, which is tolerated up to
+/// `max_swing_distance`; longer swings require a stop. The stationary time sums the swing
+/// times of every transition made at standstill.
+pub fn analyze_steering(
+    model: &VehicleModel,
+    path: &Path,
+    limits: &SteeringLimits,
+) -> SteeringReport {
+    let mut transitions = Vec::new();
+    let mut stationary_time = 0.;
+
+    for index in 1..path.len() {
+        let (previous, next) = (&path[index - 1], &path[index]);
+        let from_degree = model.steering_angle_degree(previous.steering);
+        let to_degree = model.steering_angle_degree(next.steering);
+        if from_degree == to_degree {
+            continue;
+        }
+
+        let swing_time = (to_degree - from_degree).abs() / limits.max_rate_degree;
+        let at_cusp = previous.gear != next.gear;
+        let swing_distance = if at_cusp {
+            0.
+        } else {
+            swing_time * limits.speed
+        };
+        let requires_stop = !at_cusp && swing_distance > limits.max_swing_distance;
+        if at_cusp || requires_stop {
+            stationary_time += swing_time;
+        }
+
+        transitions.push(Transition {
+            index,
+            from_degree,
+            to_degree,
+            swing_time,
+            swing_distance,
+            at_cusp,
+            requires_stop,
+        });
+    }
+
+    SteeringReport {
+        transitions,
+        stationary_time,
+    }
+}
+
+/// Time spent driving the path at `speed`, plus the stationary time of its transitions.
+pub fn execution_time(model: &VehicleModel, path: &Path, limits: &SteeringLimits) -> f64 {
+    let driving: f64 = model.commands(path).iter().map(|c| c.distance).sum::<f64>() / limits.speed;
+    driving + analyze_steering(model, path, limits).stationary_time
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vehicle::ReferencePoint;
+    use crate::{Gear, PathElement, Steering};
+
+    #[test]
+    fn cusps_and_long_swings_are_made_at_standstill() {
+        let model = VehicleModel::new(2., 30., ReferencePoint::RearAxle);
+        let limits = SteeringLimits {
+            max_rate_degree: 60.,
+            speed: 2.,
+            max_swing_distance: 1.5,
+        };
+        let path: Path = [
+            (Steering::Left, Gear::Forward),
+            (Steering::Straight, Gear::Forward),
+            (Steering::Right, Gear::Forward),
+            (Steering::Left, Gear::Backwards),
+            (Steering::Left, Gear::Backwards),
+            (Steering::Right, Gear::Backwards),
+        ]
+        .map(|(steering, gear)| PathElement::create(1., steering, gear))
+        .to_vec();
+
+        let report = analyze_steering(&model, &path, &limits);
+        let summary: Vec<_> = report
+            .transitions
+            .iter()
+            .map(|t| (t.index, t.at_cusp, t.requires_stop))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, false, false),
+                (2, false, false),
+                (3, true, false),
+                (5, false, true),
+            ]
+        );
+        assert!((report.transitions[0].swing_distance - 1.).abs() < 1e-9);
+        assert_eq!(report.transitions[2].swing_distance, 0.);
+        // The 60° swings at the cusp and before the last element take a second each.
+        assert!((report.stationary_time - 2.).abs() < 1e-9);
+        assert!(!report.is_feasible());
+        assert_eq!(
+            report.infeasible().map(|t| t.index).collect::<Vec<_>>(),
+            [5]
+        );
+
+        let driving = 6. * model.turning_radius() / limits.speed;
+        assert!((execution_time(&model, &path, &limits) - driving - 2.).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,120 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::collision::Footprint;
+use crate::polygon::Polygon;
+use crate::trajectory;
+use crate::utils;
+use crate::{Path, Pose, Steering};
+
+/// A union of overlapping convex pieces, not an outline: summing the areas of `polygons`
+/// overstates the swept area.
+#[derive(Debug, Clone)]
+pub struct SweptCover {
+    pub polygons: Vec<Polygon>,
+}
+
+impl SweptCover {
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        self.polygons.iter().any(|polygon| polygon.contains(x, y))
+    }
+}
+
+/// A conservative cover of the area the footprint sweeps along the path: every footprint
+/// along the path lies inside it, and it extends at most about `tolerance` beyond the swept
+/// area around arcs.
+///
+/// Every path element is covered separately, so the motion reversing at a cusp simply
+/// starts a new run of polygons from the cusp pose. Straights are covered exactly by the
+/// convex hull of the first and last footprint. Arcs are split into angular steps whose
+/// outer chord error stays below `tolerance`; each step also includes the middle footprint
+/// pushed out to the tangent lines so the union contains the whole swept area.
+pub fn swept_cover(
+    start: Pose,
+    path: &Path,
+    footprint: &Footprint,
+    radius: f64,
+    tolerance: f64,
+) -> SweptCover {
+    let mut polygons = vec![footprint.polygon().transformed(&start)];
+
+    let mut pose = start;
+    for e in path {
+        let length = trajectory::element_length(e, radius);
+        match e.steering {
+            Steering::Straight => {
+                let end = trajectory::advance(&pose, e, length, radius);
+                polygons.push(hull_of(&[
+                    footprint.corners(&pose),
+                    footprint.corners(&end),
+                ]));
+            }
+            Steering::Left | Steering::Right => {
+                let reach = radius + footprint.bounding_radius();
+                let max_step =
+                    (2. * (1. - (tolerance.max(1e-9) / reach).min(1.)).acos()).min(FRAC_PI_2);
+                let count = (e.param.abs() / max_step).ceil().max(1.) as usize;
+                let step = length / count as f64;
+                let center = turning_center(&pose, e.steering, radius);
+                let scale = 1. / (e.param.abs() / count as f64 / 2.).cos();
+
+                for i in 0..count {
+                    let from = trajectory::advance(&pose, e, step * i as f64, radius);
+                    let mid = trajectory::advance(&pose, e, step * (i as f64 + 0.5), radius);
+                    let to = trajectory::advance(&pose, e, step * (i as f64 + 1.), radius);
+                    let bulge = footprint.corners(&mid).map(|(x, y)| {
+                        (
+                            center.0 + (x - center.0) * scale,
+                            center.1 + (y - center.1) * scale,
+                        )
+                    });
+                    polygons.push(hull_of(&[
+                        footprint.corners(&from),
+                        bulge,
+                        footprint.corners(&to),
+                    ]));
+                }
+            }
+        }
+        pose = trajectory::advance(&pose, e, length, radius);
+    }
+
+    SweptCover { polygons }
+}
+
+fn turning_center(pose: &Pose, steering: Steering, radius: f64) -> (f64, f64) {
+    let side = if steering == Steering::Left { 1. } else { -1. };
+    utils::local_to_world(pose, 0., side * radius)
+}
+
+fn hull_of(corners: &[[(f64, f64); 4]]) -> Polygon {
+    Polygon::convex_hull(&corners.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_optimal_path_for_radius;
+    use crate::sampling::{Bounds, Rng};
+
+    #[test]
+    fn cover_contains_every_footprint_along_the_path() {
+        let footprint = Footprint::new(3., 1., 1.8);
+        let bounds = Bounds::new(-8., 8., -8., 8.);
+        let mut rng = Rng::new(11);
+        let radius = 2.;
+        for _ in 0..30 {
+            let (start, goal) = (bounds.sample(&mut rng), bounds.sample(&mut rng));
+            let Some(path) = get_optimal_path_for_radius(start, goal, radius) else {
+                continue;
+            };
+            let cover = swept_cover(start, &path, &footprint, radius, 0.05);
+            // Points just inside the footprint, so boundary rounding does not count as a miss.
+            let inner = Footprint::new(2.99, 0.99, 1.78);
+            for (_, pose) in trajectory::sample(start, &path, radius, 0.05) {
+                for (x, y) in inner.corners(&pose) {
+                    assert!(cover.contains(x, y), "({x}, {y}) at {pose:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::utils::Se2;
+use crate::{Gear, Path, PathElement, Pose, Steering};
+
+pub fn element_length(element: &PathElement, radius: f64) -> f64 {
+    element.param.abs() * radius
+}
+
+pub fn total_length(path: &Path, radius: f64) -> f64 {
+    path.iter().map(|e| element_length(e, radius)).sum()
+}
+
+/// The motion along the element is composed onto `pose` as a rigid transform in its frame.
+pub fn advance(pose: &Pose, element: &PathElement, distance: f64, radius: f64) -> Pose {
+    let direction = match element.gear {
+        Gear::Forward => 1.,
+        Gear::Backwards => -1.,
+    };
+    let s = direction * distance;
+
+    let motion = match element.steering {
+        Steering::Straight => Se2::new(s, 0., 0.),
+        Steering::Left | Steering::Right => {
+            let curvature = if element.steering == Steering::Left {
+                1.
+            } else {
+                -1.
+            };
+            let turn = curvature * s / radius;
+            Se2::new(
+                curvature * radius * turn.sin(),
+                curvature * radius * (1. - turn.cos()),
+                turn,
+            )
+        }
+    };
+
+    Se2::from_pose(pose).compose(&motion).normalized().to_pose()
+}
+
+pub fn end_pose(start: Pose, path: &Path, radius: f64) -> Pose {
+    path.iter().fold(start, |pose, e| {
+        advance(&pose, e, element_length(e, radius), radius)
+    })
+}
+
+pub fn pose_at(start: Pose, path: &Path, radius: f64, arc_length: f64) -> Pose {
+    let mut pose = start;
+    let mut remaining = arc_length.max(0.);
+    for e in path {
+        let length = element_length(e, radius);
+        if remaining <= length {
+            return advance(&pose, e, remaining, radius);
+        }
+        pose = advance(&pose, e, length, radius);
+        remaining -= length;
+    }
+    pose
+}
+
+pub fn truncate(path: &Path, radius: f64, arc_length: f64) -> Path {
+    let mut truncated = Vec::new();
+    let mut remaining = arc_length.max(0.);
+    for e in path {
+        if remaining <= 0. {
+            break;
+        }
+        let length = element_length(e, radius).min(remaining);
+        truncated.push(PathElement {
+            param: length / radius,
+            ..e.clone()
+        });
+        remaining -= length;
+    }
+    truncated
+}
+
+pub fn sample(start: Pose, path: &Path, radius: f64, step: f64) -> Vec<(f64, Pose)> {
+    let mut samples = vec![(0., start)];
+    let mut pose = start;
+    let mut travelled = 0.;
+    for e in path {
+        let length = element_length(e, radius);
+        let count = if step > 0. {
+            (length / step).ceil().max(1.) as usize
+        } else {
+            1
+        };
+        for i in 1..=count {
+            let distance = length * i as f64 / count as f64;
+            samples.push((travelled + distance, advance(&pose, e, distance, radius)));
+        }
+        pose = advance(&pose, e, length, radius);
+        travelled += length;
+    }
+    samples
+}
+
+#[derive(Debug, Clone)]
+pub struct Interpolator {
+    pub start: Pose,
+    pub path: Path,
+    pub radius: f64,
+    length: f64,
+}
+
+impl Interpolator {
+    pub fn new(from: Pose, to: Pose, radius: f64) -> Option<Self> {
+        let path = crate::get_optimal_path_for_radius(from, to, radius)?;
+        Some(Interpolator::from_path(from, path, radius))
+    }
+
+    pub fn from_path(start: Pose, path: Path, radius: f64) -> Self {
+        let length = total_length(&path, radius);
+        Interpolator {
+            start,
+            path,
+            radius,
+            length,
+        }
+    }
+
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    pub fn at(&self, t: f64) -> Pose {
+        pose_at(
+            self.start,
+            &self.path,
+            self.radius,
+            t.clamp(0., 1.) * self.length,
+        )
+    }
+}
+
+pub fn interpolate(from: Pose, to: Pose, t: f64, radius: f64) -> Option<Pose> {
+    Interpolator::new(from, to, radius).map(|interpolator| interpolator.at(t))
+}
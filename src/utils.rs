@@ -10,6 +10,10 @@ pub fn normalize_angle_rad(theta: f64) -> f64 {
     theta
 }
 
+pub fn normalize_angle_degree(theta_degree: f64) -> f64 {
+    (theta_degree + 180.).rem_euclid(360.) - 180.
+}
+
 pub struct Polar {
     pub rho: f64,
     pub theta: f64,
@@ -25,30 +29,279 @@ pub fn degree_to_radians(degree: f64) -> f64 {
     degree * PI / 180.
 }
 
+pub fn radians_to_degree(radians: f64) -> f64 {
+    radians * 180. / PI
+}
+
+/// An angle in degrees. The typed angles keep degrees and radians apart at API boundaries
+/// and convert into each other.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+/// An angle in radians.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+impl Degrees {
+    pub fn normalized(self) -> Self {
+        Degrees(normalize_angle_degree(self.0))
+    }
+}
+
+impl Radians {
+    pub fn normalized(self) -> Self {
+        Radians(normalize_angle_rad(self.0))
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(angle: Radians) -> Self {
+        Degrees(radians_to_degree(angle.0))
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(angle: Degrees) -> Self {
+        Radians(degree_to_radians(angle.0))
+    }
+}
+
+/// The heading is stored in radians, so poses built from radians reach the planner without
+/// any conversion. Degrees are converted once on construction.
 #[derive(Debug, Clone, Copy)]
 pub struct Pose {
     pub x: f64,
     pub y: f64,
-    pub theta_degree: f64,
+    pub theta_radians: f64,
 }
 
-pub fn change_of_basis(p1: &Pose, p2: &Pose) -> Pose {
-    let theta1_radians = degree_to_radians(p1.theta_degree);
+impl Pose {
+    /// Accepts either typed angle: `Pose::new(1., 2., Radians(FRAC_PI_2))` or
+    /// `Pose::new(1., 2., Degrees(90.))`.
+    pub fn new(x: f64, y: f64, theta: impl Into<Radians>) -> Self {
+        Pose {
+            x,
+            y,
+            theta_radians: theta.into().0,
+        }
+    }
 
-    let dx = p2.x - p1.x;
-    let dy = p2.y - p1.y;
+    pub fn from_radians(x: f64, y: f64, theta_radians: f64) -> Self {
+        Pose {
+            x,
+            y,
+            theta_radians,
+        }
+    }
 
-    let cos_theta1 = theta1_radians.cos();
-    let sin_theta1 = theta1_radians.sin();
+    pub fn from_degrees(x: f64, y: f64, theta_degree: f64) -> Self {
+        Pose::new(x, y, Degrees(theta_degree))
+    }
 
-    let new_x = dx * cos_theta1 + dy * sin_theta1;
-    let new_y = -dx * sin_theta1 + dy * cos_theta1;
+    pub fn theta(&self) -> Radians {
+        Radians(self.theta_radians)
+    }
+
+    pub fn heading(&self) -> Degrees {
+        self.theta().into()
+    }
 
-    let new_theta_degree = p2.theta_degree - p1.theta_degree;
+    pub fn theta_degree(&self) -> f64 {
+        radians_to_degree(self.theta_radians)
+    }
+}
 
+/// A rigid transform of the plane with its rotation in radians. `a.compose(&b)` applies `b`
+/// in the frame of `a`, so poses compose as frames: the world pose of a pose `b` expressed
+/// relative to `a` is `a.compose(&b)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Se2 {
+    pub x: f64,
+    pub y: f64,
+    pub theta: f64,
+}
+
+impl Se2 {
+    pub fn new(x: f64, y: f64, theta: f64) -> Self {
+        Se2 { x, y, theta }
+    }
+
+    pub fn identity() -> Self {
+        Se2::default()
+    }
+
+    pub fn from_pose(pose: &Pose) -> Self {
+        Se2::new(pose.x, pose.y, pose.theta_radians)
+    }
+
+    pub fn to_pose(&self) -> Pose {
+        Pose::from_radians(self.x, self.y, self.theta)
+    }
+
+    pub fn compose(&self, other: &Se2) -> Se2 {
+        let (x, y) = self.transform_point(other.x, other.y);
+        Se2::new(x, y, self.theta + other.theta)
+    }
+
+    pub fn inverse(&self) -> Se2 {
+        let (sin, cos) = self.theta.sin_cos();
+        Se2::new(
+            -self.x * cos - self.y * sin,
+            self.x * sin - self.y * cos,
+            -self.theta,
+        )
+    }
+
+    /// `other` expressed in the frame of `self`, so that `self.compose(&self.relative(other))`
+    /// is `other`.
+    pub fn relative(&self, other: &Se2) -> Se2 {
+        let (sin, cos) = self.theta.sin_cos();
+        let dx = other.x - self.x;
+        let dy = other.y - self.y;
+        Se2::new(
+            dx * cos + dy * sin,
+            -dx * sin + dy * cos,
+            other.theta - self.theta,
+        )
+    }
+
+    pub fn transform_point(&self, x: f64, y: f64) -> (f64, f64) {
+        let (sin, cos) = self.theta.sin_cos();
+        (self.x + x * cos - y * sin, self.y + x * sin + y * cos)
+    }
+
+    pub fn normalized(&self) -> Se2 {
+        Se2::new(self.x, self.y, normalize_angle_rad(self.theta))
+    }
+}
+
+pub fn change_of_basis(p1: &Pose, p2: &Pose) -> Pose {
+    let relative = Se2::from_pose(p1).relative(&Se2::from_pose(p2));
+    Pose {
+        x: relative.x,
+        y: relative.y,
+        theta_radians: p2.theta_radians - p1.theta_radians,
+    }
+}
+
+/// Undoes `change_of_basis`: the world pose of `local`, given in the frame of `p1`.
+pub fn inverse_change_of_basis(p1: &Pose, local: &Pose) -> Pose {
+    let world = Se2::from_pose(p1).compose(&Se2::from_pose(local));
+    Pose {
+        x: world.x,
+        y: world.y,
+        theta_radians: p1.theta_radians + local.theta_radians,
+    }
+}
+
+/// `change_of_basis` with the relative heading in radians, as the path families expect it.
+pub fn change_of_basis_radians(p1: &Pose, p2: &Pose) -> (f64, f64, f64) {
+    let relative = Se2::from_pose(p1).relative(&Se2::from_pose(p2));
+    (relative.x, relative.y, p2.theta_radians - p1.theta_radians)
+}
+
+pub fn local_to_world(pose: &Pose, x: f64, y: f64) -> (f64, f64) {
+    Se2::from_pose(pose).transform_point(x, y)
+}
+
+pub fn scale_pose(pose: &Pose, scale: f64) -> Pose {
     Pose {
-        x: new_x,
-        y: new_y,
-        theta_degree: new_theta_degree,
+        x: pose.x * scale,
+        y: pose.y * scale,
+        theta_radians: pose.theta_radians,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sampling::Rng;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn transforms(seed: u64, count: usize) -> Vec<Se2> {
+        let mut rng = Rng::new(seed);
+        (0..count)
+            .map(|_| {
+                Se2::new(
+                    rng.range(-10., 10.),
+                    rng.range(-10., 10.),
+                    rng.range(-2. * PI, 2. * PI),
+                )
+            })
+            .collect()
+    }
+
+    fn assert_close(a: Se2, b: Se2) {
+        assert!(
+            (a.x - b.x).abs() < TOLERANCE
+                && (a.y - b.y).abs() < TOLERANCE
+                && normalize_angle_rad(a.theta - b.theta).abs() < TOLERANCE,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn radians_reach_the_planner_unchanged() {
+        let mut rng = Rng::new(0);
+        for _ in 0..10_000 {
+            let (a, b) = (rng.range(-PI, PI), rng.range(-PI, PI));
+            let (p1, p2) = (Pose::from_radians(1., 2., a), Pose::from_radians(3., 4., b));
+            assert_eq!(p1.theta().0, a);
+            assert_eq!(change_of_basis_radians(&p1, &p2).2, b - a);
+        }
+    }
+
+    #[test]
+    fn composition_is_associative() {
+        for abc in transforms(1, 300).chunks(3) {
+            let (a, b, c) = (abc[0], abc[1], abc[2]);
+            assert_close(a.compose(&b).compose(&c), a.compose(&b.compose(&c)));
+        }
+    }
+
+    #[test]
+    fn identity_is_neutral() {
+        for a in transforms(2, 100) {
+            assert_close(a.compose(&Se2::identity()), a);
+            assert_close(Se2::identity().compose(&a), a);
+        }
+    }
+
+    #[test]
+    fn inverse_cancels_on_both_sides() {
+        for a in transforms(3, 100) {
+            assert_close(a.compose(&a.inverse()), Se2::identity());
+            assert_close(a.inverse().compose(&a), Se2::identity());
+        }
+    }
+
+    #[test]
+    fn relative_round_trips_through_compose() {
+        for ab in transforms(4, 200).chunks(2) {
+            let (a, b) = (ab[0], ab[1]);
+            assert_close(a.compose(&a.relative(&b)), b);
+            assert_close(a.relative(&b), a.inverse().compose(&b));
+        }
+    }
+
+    #[test]
+    fn inverse_change_of_basis_undoes_change_of_basis() {
+        for ab in transforms(5, 200).chunks(2) {
+            let (p1, p2) = (ab[0].to_pose(), ab[1].to_pose());
+            let local = change_of_basis(&p1, &p2);
+            let world = inverse_change_of_basis(&p1, &local);
+            assert_close(Se2::from_pose(&world), Se2::from_pose(&p2));
+        }
+    }
+
+    #[test]
+    fn transform_point_matches_composition() {
+        for ab in transforms(6, 200).chunks(2) {
+            let (a, b) = (ab[0], ab[1]);
+            let (x, y) = a.transform_point(b.x, b.y);
+            let composed = a.compose(&b);
+            assert!((x - composed.x).abs() < TOLERANCE && (y - composed.y).abs() < TOLERANCE);
+        }
     }
 }
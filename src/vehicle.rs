@@ -0,0 +1,95 @@
+use crate::utils;
+use crate::{Gear, Path, PathElement, Steering};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReferencePoint {
+    RearAxle,
+    FrontAxle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VehicleModel {
+    pub wheelbase: f64,
+    pub max_steering_angle_degree: f64,
+    pub reference: ReferencePoint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActuatorCommand {
+    pub steering_angle_degree: f64,
+    pub gear: Gear,
+    pub distance: f64,
+}
+
+impl VehicleModel {
+    pub fn new(wheelbase: f64, max_steering_angle_degree: f64, reference: ReferencePoint) -> Self {
+        VehicleModel {
+            wheelbase,
+            max_steering_angle_degree,
+            reference,
+        }
+    }
+
+    /// Bicycle model: the rear axle circles at `wheelbase / tan(delta)` and the front axle at
+    /// `wheelbase / sin(delta)` around the same center.
+    pub fn turning_radius(&self) -> f64 {
+        let delta = utils::degree_to_radians(self.max_steering_angle_degree.abs());
+        match self.reference {
+            ReferencePoint::RearAxle => self.wheelbase / delta.tan(),
+            ReferencePoint::FrontAxle => self.wheelbase / delta.sin(),
+        }
+    }
+
+    pub fn steering_angle_degree(&self, steering: Steering) -> f64 {
+        match steering {
+            Steering::Left => self.max_steering_angle_degree.abs(),
+            Steering::Right => -self.max_steering_angle_degree.abs(),
+            Steering::Straight => 0.,
+        }
+    }
+
+    pub fn command(&self, element: &PathElement) -> ActuatorCommand {
+        ActuatorCommand {
+            steering_angle_degree: self.steering_angle_degree(element.steering),
+            gear: element.gear,
+            distance: element.param.abs() * self.turning_radius(),
+        }
+    }
+
+    pub fn commands(&self, path: &Path) -> Vec<ActuatorCommand> {
+        path.iter().map(|e| self.command(e)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_radius_follows_the_bicycle_model() {
+        let rear = VehicleModel::new(2.7, 30., ReferencePoint::RearAxle);
+        let front = VehicleModel::new(2.7, 30., ReferencePoint::FrontAxle);
+        assert!((rear.turning_radius() - 2.7 * 3f64.sqrt()).abs() < 1e-9);
+        assert!((front.turning_radius() - 5.4).abs() < 1e-9);
+        // Both axles circle the same center, one wheelbase apart.
+        assert!(
+            (front.turning_radius().powi(2) - rear.turning_radius().powi(2) - 2.7 * 2.7).abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn commands_scale_parameters_by_the_turning_radius() {
+        let vehicle = VehicleModel::new(2.7, -30., ReferencePoint::FrontAxle);
+        let commands = vehicle.commands(&vec![
+            PathElement::create(0.5, Steering::Right, Gear::Forward),
+            PathElement::create(-2., Steering::Straight, Gear::Forward),
+        ]);
+        let expected = [(-30., Gear::Forward, 2.7), (0., Gear::Backwards, 10.8)];
+        assert_eq!(commands.len(), expected.len());
+        for (command, (angle, gear, distance)) in commands.iter().zip(expected) {
+            assert_eq!((command.steering_angle_degree, command.gear), (angle, gear));
+            assert!((command.distance - distance).abs() < 1e-9);
+        }
+    }
+}